@@ -0,0 +1,335 @@
+//! A controllable credentials provider for testing token-based authentication.
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use futures::Stream;
+use futures::channel::mpsc::{UnboundedSender, unbounded};
+use redis::{BasicAuth, RedisResult, StreamingCredentialsProvider};
+
+#[derive(Default)]
+struct MockCredentialsState {
+    seeded: VecDeque<RedisResult<BasicAuth>>,
+    on_invalidate: VecDeque<RedisResult<BasicAuth>>,
+    subscribers: Vec<UnboundedSender<RedisResult<BasicAuth>>>,
+    subscribe_count: usize,
+    invalidate_count: usize,
+}
+
+impl MockCredentialsState {
+    fn emit(&mut self, credentials: RedisResult<BasicAuth>) {
+        self.subscribers
+            .retain(|sender| sender.unbounded_send(credentials.clone()).is_ok());
+        self.seeded = VecDeque::from([credentials]);
+    }
+}
+
+/// A [`StreamingCredentialsProvider`] whose credentials are controlled by the test.
+///
+/// Every new subscriber first receives the next pre-seeded result, followed by every result
+/// passed to [`emit`](Self::emit) while it is subscribed. The seeded results are consumed in order,
+/// except for the last one, which is given to all the later subscribers. An emitted result replaces
+/// the seeded ones, so later subscribers receive it as well.
+///
+/// Results queued with [`emit_on_invalidate`](Self::emit_on_invalidate) are emitted one per
+/// [`invalidate`](StreamingCredentialsProvider::invalidate). This simulates a provider that fetches new
+/// credentials after the server rejected the current ones, so that a connection which subscribes again
+/// receives the new credentials. This can be used to simulate credential rotation, expiry and refresh failures.
+/// Clones share the same state.
+///
+/// # Example
+///
+/// ```rust
+/// use redis::{AsyncConnectionConfig, BasicAuth};
+/// use redis_test::credentials::MockCredentialsProvider;
+///
+/// let provider = MockCredentialsProvider::new(vec![Ok(BasicAuth::new(
+///     "user".to_string(),
+///     "initial-token".to_string(),
+/// ))]);
+/// let config = AsyncConnectionConfig::new().set_credentials_provider(provider.clone());
+///
+/// // Later, rotate the credentials of all the connections using the provider.
+/// provider.emit(Ok(BasicAuth::new("user".to_string(), "rotated-token".to_string())));
+/// ```
+#[derive(Clone, Default)]
+pub struct MockCredentialsProvider {
+    state: Arc<Mutex<MockCredentialsState>>,
+}
+
+impl MockCredentialsProvider {
+    /// Creates a provider which gives the results in `seeded` to the new subscribers, one per subscriber.
+    pub fn new(seeded: Vec<RedisResult<BasicAuth>>) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MockCredentialsState {
+                seeded: seeded.into(),
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Sends `credentials` to all the current subscribers, and makes it the first result of the later ones.
+    pub fn emit(&self, credentials: RedisResult<BasicAuth>) {
+        self.state.lock().unwrap().emit(credentials);
+    }
+
+    /// Queues `credentials` to be emitted by the next [`invalidate`](StreamingCredentialsProvider::invalidate)
+    /// that has no earlier result queued.
+    pub fn emit_on_invalidate(&self, credentials: RedisResult<BasicAuth>) {
+        self.state
+            .lock()
+            .unwrap()
+            .on_invalidate
+            .push_back(credentials);
+    }
+
+    /// Returns how many times [`subscribe`](StreamingCredentialsProvider::subscribe) was called.
+    pub fn subscribe_count(&self) -> usize {
+        self.state.lock().unwrap().subscribe_count
+    }
+
+    /// Returns how many times [`invalidate`](StreamingCredentialsProvider::invalidate) was called.
+    pub fn invalidate_count(&self) -> usize {
+        self.state.lock().unwrap().invalidate_count
+    }
+
+    /// Returns how many subscribers are still listening for credentials.
+    pub fn active_subscribers(&self) -> usize {
+        let mut state = self.state.lock().unwrap();
+        state.subscribers.retain(|sender| !sender.is_closed());
+        state.subscribers.len()
+    }
+}
+
+impl StreamingCredentialsProvider for MockCredentialsProvider {
+    fn subscribe(&self) -> Pin<Box<dyn Stream<Item = RedisResult<BasicAuth>> + Send + 'static>> {
+        let (sender, receiver) = unbounded();
+        let mut state = self.state.lock().unwrap();
+        state.subscribe_count += 1;
+        let credentials = if state.seeded.len() > 1 {
+            state.seeded.pop_front()
+        } else {
+            state.seeded.front().cloned()
+        };
+        if let Some(credentials) = credentials {
+            // The receiver is alive, so this cannot fail.
+            let _ = sender.unbounded_send(credentials);
+        }
+        state.subscribers.push(sender);
+        Box::pin(receiver)
+    }
+
+    fn invalidate(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.invalidate_count += 1;
+        if let Some(credentials) = state.on_invalidate.pop_front() {
+            state.emit(credentials);
+        }
+    }
+}
+
+impl std::fmt::Debug for MockCredentialsProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("MockCredentialsProvider")
+            .field("seeded", &state.seeded.len())
+            .field("on_invalidate", &state.on_invalidate.len())
+            .field("subscribers", &state.subscribers.len())
+            .field("subscribe_count", &state.subscribe_count)
+            .field("invalidate_count", &state.invalidate_count)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use futures::executor::block_on;
+    use redis::{ErrorKind, RedisError};
+
+    fn credentials(password: &str) -> BasicAuth {
+        BasicAuth::new("user".to_string(), password.to_string())
+    }
+
+    #[test]
+    fn seeded_results_are_consumed_across_subscriptions() {
+        let provider = MockCredentialsProvider::new(vec![
+            Ok(credentials("token-1")),
+            Err(RedisError::from((
+                ErrorKind::AuthenticationFailed,
+                "Expired",
+            ))),
+            Ok(credentials("token-2")),
+        ]);
+
+        let first = block_on(provider.subscribe().next()).unwrap().unwrap();
+        assert_eq!(first.password(), "token-1");
+        let second = block_on(provider.subscribe().next()).unwrap().unwrap_err();
+        assert_eq!(second.kind(), ErrorKind::AuthenticationFailed);
+        // The last seeded result is kept for all the later subscribers
+        for _ in 0..2 {
+            let credentials = block_on(provider.subscribe().next()).unwrap().unwrap();
+            assert_eq!(credentials.password(), "token-2");
+        }
+        assert_eq!(provider.subscribe_count(), 4);
+    }
+
+    #[test]
+    fn invalidations_are_counted() {
+        let provider = MockCredentialsProvider::new(vec![
+            Ok(credentials("token-1")),
+            Ok(credentials("token-2")),
+        ]);
+        let mut stream = provider.subscribe();
+        assert_eq!(
+            block_on(stream.next()).unwrap().unwrap().password(),
+            "token-1"
+        );
+        assert_eq!(provider.invalidate_count(), 0);
+
+        provider.clone().invalidate();
+        assert_eq!(provider.invalidate_count(), 1);
+        let fresh = block_on(provider.subscribe().next()).unwrap().unwrap();
+        assert_eq!(fresh.password(), "token-2");
+    }
+
+    #[test]
+    fn emitted_results_replace_seeded_results() {
+        let provider = MockCredentialsProvider::new(vec![
+            Ok(credentials("token-1")),
+            Ok(credentials("token-2")),
+        ]);
+        provider.emit(Ok(credentials("rotated")));
+        for _ in 0..2 {
+            let credentials = block_on(provider.subscribe().next()).unwrap().unwrap();
+            assert_eq!(credentials.password(), "rotated");
+        }
+    }
+
+    #[test]
+    fn invalidate_emits_queued_results() {
+        let provider = MockCredentialsProvider::new(vec![Ok(credentials("token-1"))]);
+        provider.emit_on_invalidate(Ok(credentials("token-2")));
+        let mut stream = provider.subscribe();
+        assert_eq!(
+            block_on(stream.next()).unwrap().unwrap().password(),
+            "token-1"
+        );
+
+        provider.invalidate();
+        assert_eq!(
+            block_on(stream.next()).unwrap().unwrap().password(),
+            "token-2"
+        );
+        let fresh = block_on(provider.subscribe().next()).unwrap().unwrap();
+        assert_eq!(fresh.password(), "token-2");
+
+        // Without a queued result, invalidating keeps the current credentials
+        provider.invalidate();
+        let current = block_on(provider.subscribe().next()).unwrap().unwrap();
+        assert_eq!(current.password(), "token-2");
+        assert_eq!(provider.invalidate_count(), 2);
+    }
+
+    /// Reads one byte at a time, so that the parser stops right after the first complete command.
+    struct ByteByByte<'a>(&'a [u8]);
+
+    impl std::io::Read for ByteByByte<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((byte, rest)), Some(slot)) => {
+                    *slot = *byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn connection_reauthenticates_with_credentials_emitted_on_invalidate() {
+        use redis::aio::MultiplexedConnection;
+        use redis::{AsyncConnectionConfig, RedisConnectionInfo, Value};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let (client_half, mut server_half) = tokio::io::duplex(4096);
+        let passwords = Arc::new(Mutex::new(Vec::<String>::new()));
+
+        // Accepts any AUTH, but `token-1` has been revoked, so other commands need `token-2`.
+        let seen_passwords = passwords.clone();
+        tokio::spawn(async move {
+            let mut buffer = Vec::new();
+            let mut chunk = [0; 1024];
+            while let Ok(read @ 1..) = server_half.read(&mut chunk).await {
+                buffer.extend_from_slice(&chunk[..read]);
+                loop {
+                    let mut reader = ByteByByte(&buffer[..]);
+                    let Ok(Value::Array(args)) = redis::Parser::new().parse_value(&mut reader)
+                    else {
+                        break;
+                    };
+                    let consumed = buffer.len() - reader.0.len();
+                    buffer.drain(..consumed);
+
+                    let args: Vec<String> = args
+                        .into_iter()
+                        .map(|arg| redis::from_redis_value(arg).unwrap())
+                        .collect();
+                    let reply: &[u8] = if args[0] == "AUTH" {
+                        seen_passwords
+                            .lock()
+                            .unwrap()
+                            .push(args.last().unwrap().clone());
+                        b"+OK\r\n"
+                    } else if seen_passwords.lock().unwrap().last().unwrap() == "token-2" {
+                        b"$5\r\nvalue\r\n"
+                    } else {
+                        b"-WRONGPASS invalid username-password pair or user is disabled.\r\n"
+                    };
+                    server_half.write_all(reply).await.unwrap();
+                }
+            }
+        });
+
+        let provider = MockCredentialsProvider::new(vec![Ok(credentials("token-1"))]);
+        provider.emit_on_invalidate(Ok(credentials("token-2")));
+        let config = AsyncConnectionConfig::new().set_credentials_provider(provider.clone());
+        let (mut conn, driver) = MultiplexedConnection::new_with_config(
+            &RedisConnectionInfo::default().set_skip_set_lib_name(),
+            client_half,
+            config,
+        )
+        .await
+        .unwrap();
+        tokio::spawn(driver);
+
+        let value: String = redis::cmd("GET")
+            .arg("key")
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+
+        assert_eq!(value, "value");
+        assert_eq!(provider.invalidate_count(), 1);
+        assert_eq!(passwords.lock().unwrap()[0], "token-1");
+        assert!(passwords.lock().unwrap().contains(&"token-2".to_string()));
+    }
+
+    #[test]
+    fn emitted_results_reach_active_subscribers() {
+        let provider = MockCredentialsProvider::default();
+        let mut first = provider.subscribe();
+        let second = provider.subscribe();
+        assert_eq!(provider.active_subscribers(), 2);
+
+        drop(second);
+        assert_eq!(provider.active_subscribers(), 1);
+
+        provider.clone().emit(Ok(credentials("rotated")));
+        let rotated = block_on(first.next()).unwrap().unwrap();
+        assert_eq!(rotated.password(), "rotated");
+    }
+}
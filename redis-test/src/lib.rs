@@ -11,6 +11,9 @@
 //!   a local Redis cluster with multiple nodes and replicas.
 //! * **Sentinels**: [`sentinel::RedisSentinelCluster`] provides functionality to spawn
 //!   and configure a local Redis sentinel cluster.
+//! * **Credentials Providers**: `credentials::MockCredentialsProvider` (behind the
+//!   `token-based-authentication` feature) is a controllable `StreamingCredentialsProvider`
+//!   for testing credential rotation and failures.
 //!
 //! # Example (Mock Connection)
 //!
@@ -32,6 +35,8 @@
 //! ```
 
 pub mod cluster;
+#[cfg(feature = "token-based-authentication")]
+pub mod credentials;
 pub mod sentinel;
 pub mod server;
 pub mod utils;
@@ -1,6 +1,8 @@
 use super::{AsyncPushSender, HandleContainer, RedisFuture};
 #[cfg(feature = "cache-aio")]
 use crate::caching::CacheManager;
+#[cfg(feature = "token-based-authentication")]
+use crate::client::DEFAULT_CREDENTIALS_REFRESH_TIMEOUT;
 use crate::{
     AsyncConnectionConfig, Client, Cmd, Pipeline, PushInfo, PushKind, ToRedisArgs,
     aio::{ConnectionLike, MultiplexedConnection, Runtime},
@@ -51,6 +53,9 @@ pub struct ConnectionManagerConfig {
     /// Optional credentials provider for dynamic authentication (e.g., token-based authentication)
     #[cfg(feature = "token-based-authentication")]
     credentials_provider: Option<std::sync::Arc<dyn crate::auth::StreamingCredentialsProvider>>,
+    /// Maximum time to wait for new credentials after the server rejected the current ones
+    #[cfg(feature = "token-based-authentication")]
+    credentials_refresh_timeout: Duration,
 }
 
 impl std::fmt::Debug for ConnectionManagerConfig {
@@ -71,6 +76,8 @@ impl std::fmt::Debug for ConnectionManagerConfig {
             write_backpressure_boundary,
             #[cfg(feature = "token-based-authentication")]
             credentials_provider,
+            #[cfg(feature = "token-based-authentication")]
+            credentials_refresh_timeout,
         } = &self;
         let mut str = f.debug_struct("ConnectionManagerConfig");
         str.field("exponent_base", &exponent_base)
@@ -103,7 +110,8 @@ impl std::fmt::Debug for ConnectionManagerConfig {
             } else {
                 &"not set"
             },
-        );
+        )
+        .field("credentials_refresh_timeout", &credentials_refresh_timeout);
 
         str.finish()
     }
@@ -308,11 +316,31 @@ impl ConnectionManagerConfig {
     /// # }
     /// ```
     #[cfg(feature = "token-based-authentication")]
-    pub fn set_credentials_provider<P>(mut self, provider: P) -> Self
+    pub fn set_credentials_provider<P>(self, provider: P) -> Self
     where
         P: crate::auth::StreamingCredentialsProvider + 'static,
     {
-        self.credentials_provider = Some(std::sync::Arc::new(provider));
+        self.set_shared_credentials_provider(std::sync::Arc::new(provider))
+    }
+
+    /// Sets a credentials provider that is shared with other connections.
+    ///
+    /// See [`crate::AsyncConnectionConfig::set_shared_credentials_provider`].
+    #[cfg(feature = "token-based-authentication")]
+    pub fn set_shared_credentials_provider(
+        mut self,
+        provider: std::sync::Arc<dyn crate::auth::StreamingCredentialsProvider>,
+    ) -> Self {
+        self.credentials_provider = Some(provider);
+        self
+    }
+
+    /// Sets how long connections wait for new credentials after the server rejects the current ones.
+    ///
+    /// See [`crate::AsyncConnectionConfig::set_credentials_refresh_timeout`].
+    #[cfg(feature = "token-based-authentication")]
+    pub fn set_credentials_refresh_timeout(mut self, timeout: Duration) -> Self {
+        self.credentials_refresh_timeout = timeout;
         self
     }
 }
@@ -335,6 +363,8 @@ impl Default for ConnectionManagerConfig {
             write_backpressure_boundary: None,
             #[cfg(feature = "token-based-authentication")]
             credentials_provider: None,
+            #[cfg(feature = "token-based-authentication")]
+            credentials_refresh_timeout: DEFAULT_CREDENTIALS_REFRESH_TIMEOUT,
         }
     }
 }
@@ -506,6 +536,10 @@ impl ConnectionManager {
         if let Some(credentials_provider) = config.credentials_provider {
             connection_config.credentials_provider = Some(credentials_provider);
         }
+        #[cfg(feature = "token-based-authentication")]
+        {
+            connection_config.credentials_refresh_timeout = config.credentials_refresh_timeout;
+        }
 
         let (oneshot_sender, oneshot_receiver) = oneshot::channel();
         let _task_handle = HandleContainer::new(
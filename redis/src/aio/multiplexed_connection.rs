@@ -17,6 +17,7 @@ use ::tokio::{
 };
 #[cfg(feature = "token-based-authentication")]
 use {
+    crate::auth::StreamingCredentialsProvider,
     arcstr::ArcStr,
     log::{debug, error, warn},
 };
@@ -550,6 +551,11 @@ pub struct MultiplexedConnection {
     // This handle ensures that once all the clones of the connection will be dropped, the underlying task will stop.
     // It is only set for connections that use a credentials provider for token-based authentication.
     _credentials_subscription_task_handle: Option<SharedHandleContainer>,
+    // Used to fetch fresh credentials when the server rejects the current ones.
+    #[cfg(feature = "token-based-authentication")]
+    credentials_provider: Option<Arc<dyn StreamingCredentialsProvider>>,
+    #[cfg(feature = "token-based-authentication")]
+    credentials_refresh_timeout: Duration,
 }
 
 impl Debug for MultiplexedConnection {
@@ -565,6 +571,10 @@ impl Debug for MultiplexedConnection {
                 cache_manager: _,
             #[cfg(feature = "token-based-authentication")]
                 _credentials_subscription_task_handle: _,
+            #[cfg(feature = "token-based-authentication")]
+                credentials_provider: _,
+            #[cfg(feature = "token-based-authentication")]
+                credentials_refresh_timeout: _,
         } = self;
 
         f.debug_struct("MultiplexedConnection")
@@ -644,7 +654,7 @@ impl MultiplexedConnection {
             // Retrieve the initial credentials from the provider and apply them to the connection info
             match credentials_provider.subscribe().next().await {
                 Some(Ok(credentials)) => {
-                    connection_info.username = Some(ArcStr::from(credentials.username));
+                    connection_info.username = credentials.username.map(ArcStr::from);
                     connection_info.password = Some(ArcStr::from(credentials.password));
                 }
                 Some(Err(err)) => {
@@ -697,6 +707,10 @@ impl MultiplexedConnection {
             cache_manager: cache_manager_opt,
             #[cfg(feature = "token-based-authentication")]
             _credentials_subscription_task_handle: None,
+            #[cfg(feature = "token-based-authentication")]
+            credentials_provider: None,
+            #[cfg(feature = "token-based-authentication")]
+            credentials_refresh_timeout: config.credentials_refresh_timeout,
         };
 
         // Set up streaming credentials subscription if provider is available
@@ -737,6 +751,7 @@ impl MultiplexedConnection {
                     _credentials_subscription_task_handle: Some(SharedHandleContainer::new(
                         subscription_task_handle,
                     )),
+                    credentials_provider: Some(streaming_provider),
                     ..con
                 },
                 driver,
@@ -759,7 +774,31 @@ impl MultiplexedConnection {
 
     /// Sends an already encoded (packed) command into the TCP socket and
     /// reads the single response from it.
+    ///
+    /// If the connection uses a credentials provider and the server replies with `NOAUTH` or `WRONGPASS`,
+    /// the provider's credentials are invalidated, the connection re-authenticates with fresh credentials
+    /// and the command is retried once. If re-authentication fails, the original error is returned.
     pub async fn send_packed_command(&mut self, cmd: &Cmd) -> RedisResult<Value> {
+        let result = self.send_packed_command_once(cmd).await;
+        #[cfg(feature = "token-based-authentication")]
+        if let Ok(Value::ServerError(err)) = &result
+            && is_authentication_error(err)
+            && let Some(provider) = self.credentials_provider.clone()
+        {
+            match self.refresh_authentication(provider.as_ref()).await {
+                Ok(()) => return self.send_packed_command_once(cmd).await,
+                Err(refresh_err) => {
+                    error!(
+                        "Failed to re-authenticate after {}: {refresh_err}",
+                        err.code()
+                    );
+                }
+            }
+        }
+        result
+    }
+
+    async fn send_packed_command_once(&mut self, cmd: &Cmd) -> RedisResult<Value> {
         let _permit = if cmd.skip_concurrency_limit {
             None
         } else if let Some(limiter) = &self.concurrency_limiter {
@@ -808,6 +847,9 @@ impl MultiplexedConnection {
     /// Sends multiple already encoded (packed) command into the TCP socket
     /// and reads `count` responses from it.  This is used to implement
     /// pipelining.
+    ///
+    /// Unlike [`send_packed_command`](Self::send_packed_command), a pipeline is not retried
+    /// when the server replies with `NOAUTH` or `WRONGPASS`, since some of its commands may have been executed.
     pub async fn send_packed_commands(
         &mut self,
         cmd: &crate::Pipeline,
@@ -998,14 +1040,46 @@ impl MultiplexedConnection {
         &mut self,
         credentials: &crate::auth::BasicAuth,
     ) -> RedisResult<()> {
-        let mut auth_cmd =
-            crate::connection::authenticate_cmd(Some(&credentials.username), &credentials.password);
+        let mut auth_cmd = credentials.auth_cmd();
         auth_cmd.skip_concurrency_limit = true;
-        self.send_packed_command(&auth_cmd)
+        self.send_packed_command_once(&auth_cmd)
             .await?
             .extract_error()
             .map(|_| ())
     }
+
+    /// Discard the provider's cached credentials and re-authenticate with the next credentials it yields.
+    ///
+    /// Waiting for the credentials is bounded by the credentials refresh timeout.
+    async fn refresh_authentication(
+        &mut self,
+        provider: &dyn StreamingCredentialsProvider,
+    ) -> RedisResult<()> {
+        provider.invalidate();
+        let mut stream = provider.subscribe();
+        let credentials = Runtime::locate()
+            .timeout(self.credentials_refresh_timeout, stream.next())
+            .await
+            .map_err(RedisError::from)?;
+        match credentials {
+            Some(credentials) => {
+                self.re_authenticate_with_credentials(&credentials?).await?;
+                debug!(
+                    "Re-authenticated async connection after the server rejected its credentials"
+                );
+                Ok(())
+            }
+            None => Err(RedisError::from((
+                ErrorKind::AuthenticationFailed,
+                "Credentials stream closed unexpectedly before yielding credentials!",
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "token-based-authentication")]
+fn is_authentication_error(err: &ServerError) -> bool {
+    matches!(err.code(), "NOAUTH" | "WRONGPASS")
 }
 
 #[cfg(test)]
@@ -1469,4 +1543,320 @@ mod tests {
         assert!(err.is_io_error(), "expected IO error from timeout");
         cmd_received_rx.recv().await.unwrap();
     }
+
+    #[cfg(feature = "token-based-authentication")]
+    #[tokio::test]
+    async fn test_noauth_triggers_single_reauthentication() {
+        use crate::auth::BasicAuth;
+        use futures_util::StreamExt;
+        use std::sync::Mutex;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::AsyncWriteExt;
+        use tokio_util::codec::FramedRead;
+
+        // Yields `token-N`, where N is the number of invalidations so far.
+        #[derive(Default)]
+        struct RotatingProvider {
+            invalidations: AtomicUsize,
+        }
+
+        impl StreamingCredentialsProvider for RotatingProvider {
+            fn subscribe(
+                &self,
+            ) -> Pin<Box<dyn Stream<Item = RedisResult<BasicAuth>> + Send + 'static>> {
+                let token = format!("token-{}", self.invalidations.load(Ordering::SeqCst));
+                stream::once(async move { Ok(BasicAuth::new("user".to_string(), token)) })
+                    .chain(stream::pending())
+                    .boxed()
+            }
+
+            fn invalidate(&self) {
+                self.invalidations.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let (client_half, server_half) = tokio::io::duplex(4096);
+        let (server_read, mut server_write) = tokio::io::split(server_half);
+        let passwords = Arc::new(Mutex::new(Vec::<String>::new()));
+
+        // Rejects everything but AUTH until the client authenticates with `token-1`.
+        let seen_passwords = passwords.clone();
+        tokio::spawn(async move {
+            let mut reader = FramedRead::new(server_read, ValueCodec::default());
+            while let Some(Ok(Value::Array(args))) = reader.next().await {
+                let args: Vec<String> = args
+                    .into_iter()
+                    .map(|arg| crate::from_redis_value(arg).unwrap())
+                    .collect();
+                let reply: &[u8] = if args[0] == "AUTH" {
+                    seen_passwords
+                        .lock()
+                        .unwrap()
+                        .push(args.last().unwrap().clone());
+                    b"+OK\r\n"
+                } else if seen_passwords
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .any(|p| p == "token-1")
+                {
+                    b"$5\r\nvalue\r\n"
+                } else {
+                    b"-NOAUTH Authentication required.\r\n"
+                };
+                server_write.write_all(reply).await.unwrap();
+            }
+        });
+
+        let provider = Arc::new(RotatingProvider::default());
+        let config = AsyncConnectionConfig::new()
+            .set_shared_credentials_provider(provider.clone())
+            .set_response_timeout(Some(Duration::from_secs(5)))
+            .set_connection_timeout(None);
+        let (mut conn, driver) =
+            MultiplexedConnection::new_with_config(&mock_conn_info(), client_half, config)
+                .await
+                .unwrap();
+        tokio::spawn(driver);
+
+        let value = conn
+            .send_packed_command(cmd("GET").arg("key"))
+            .await
+            .unwrap();
+
+        assert_eq!(value, Value::BulkString(b"value".to_vec()));
+        assert_eq!(provider.invalidations.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            passwords
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|p| *p == "token-1")
+                .count(),
+            1
+        );
+    }
+
+    #[cfg(feature = "token-based-authentication")]
+    #[tokio::test]
+    async fn test_credentials_refresh_timeout_bounds_wait_without_response_timeout() {
+        use crate::auth::BasicAuth;
+        use futures_util::StreamExt;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::AsyncWriteExt;
+        use tokio_util::codec::FramedRead;
+
+        // Yields credentials only until the first invalidation, after which it never yields again.
+        #[derive(Default)]
+        struct StalledProvider {
+            invalidations: AtomicUsize,
+        }
+
+        impl StreamingCredentialsProvider for StalledProvider {
+            fn subscribe(
+                &self,
+            ) -> Pin<Box<dyn Stream<Item = RedisResult<BasicAuth>> + Send + 'static>> {
+                if self.invalidations.load(Ordering::SeqCst) == 0 {
+                    stream::once(async {
+                        Ok(BasicAuth::new("user".to_string(), "token".to_string()))
+                    })
+                    .chain(stream::pending())
+                    .boxed()
+                } else {
+                    stream::pending().boxed()
+                }
+            }
+
+            fn invalidate(&self) {
+                self.invalidations.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let (client_half, server_half) = tokio::io::duplex(4096);
+        let (server_read, mut server_write) = tokio::io::split(server_half);
+
+        // Accepts AUTH but rejects every other command.
+        tokio::spawn(async move {
+            let mut reader = FramedRead::new(server_read, ValueCodec::default());
+            while let Some(Ok(Value::Array(args))) = reader.next().await {
+                let command: String = crate::from_redis_value(args[0].clone()).unwrap();
+                let reply: &[u8] = if command == "AUTH" {
+                    b"+OK\r\n"
+                } else {
+                    b"-NOAUTH Authentication required.\r\n"
+                };
+                server_write.write_all(reply).await.unwrap();
+            }
+        });
+
+        let provider = Arc::new(StalledProvider::default());
+        let config = AsyncConnectionConfig::new()
+            .set_shared_credentials_provider(provider.clone())
+            .set_credentials_refresh_timeout(Duration::from_millis(100))
+            .set_response_timeout(None)
+            .set_connection_timeout(None);
+        let (mut conn, driver) =
+            MultiplexedConnection::new_with_config(&mock_conn_info(), client_half, config)
+                .await
+                .unwrap();
+        tokio::spawn(driver);
+
+        let value = tokio::time::timeout(
+            Duration::from_secs(5),
+            conn.send_packed_command(cmd("GET").arg("key")),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        let Value::ServerError(err) = value else {
+            panic!("expected a server error, got {value:?}");
+        };
+        assert_eq!(err.code(), "NOAUTH");
+        assert_eq!(provider.invalidations.load(Ordering::SeqCst), 1);
+    }
+
+    #[cfg(all(feature = "token-based-authentication", feature = "tokio-comp"))]
+    #[tokio::test]
+    async fn test_noauth_recovers_with_polling_provider() {
+        use crate::auth::{BasicAuth, PollingStreamingProvider};
+        use crate::auth_management::RetryConfig;
+        use futures_util::StreamExt;
+        use std::sync::Mutex;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::AsyncWriteExt;
+        use tokio_util::codec::FramedRead;
+
+        let (client_half, server_half) = tokio::io::duplex(4096);
+        let (server_read, mut server_write) = tokio::io::split(server_half);
+        let passwords = Arc::new(Mutex::new(Vec::<String>::new()));
+
+        // Accepts any AUTH, but `token-1` has been revoked, so other commands need `token-2`.
+        let seen_passwords = passwords.clone();
+        tokio::spawn(async move {
+            let mut reader = FramedRead::new(server_read, ValueCodec::default());
+            while let Some(Ok(Value::Array(args))) = reader.next().await {
+                let args: Vec<String> = args
+                    .into_iter()
+                    .map(|arg| crate::from_redis_value(arg).unwrap())
+                    .collect();
+                let reply: &[u8] = if args[0] == "AUTH" {
+                    seen_passwords
+                        .lock()
+                        .unwrap()
+                        .push(args.last().unwrap().clone());
+                    b"+OK\r\n"
+                } else if seen_passwords.lock().unwrap().last().unwrap() == "token-2" {
+                    b"$5\r\nvalue\r\n"
+                } else {
+                    b"-WRONGPASS invalid username-password pair or user is disabled.\r\n"
+                };
+                server_write.write_all(reply).await.unwrap();
+            }
+        });
+
+        // Each fetch rotates the credentials, and the interval is long enough to never elapse during the test
+        let fetch_count = Arc::new(AtomicUsize::new(0));
+        let fetch_count_ref = fetch_count.clone();
+        let provider = PollingStreamingProvider::new(
+            Duration::from_secs(3600),
+            RetryConfig::default(),
+            move || {
+                let fetch = fetch_count_ref.fetch_add(1, Ordering::SeqCst) + 1;
+                async move { Ok(BasicAuth::new("user".to_string(), format!("token-{fetch}"))) }
+            },
+        );
+        let config = AsyncConnectionConfig::new()
+            .set_credentials_provider(provider)
+            .set_connection_timeout(None);
+        let (mut conn, driver) =
+            MultiplexedConnection::new_with_config(&mock_conn_info(), client_half, config)
+                .await
+                .unwrap();
+        tokio::spawn(driver);
+
+        let value = conn
+            .send_packed_command(cmd("GET").arg("key"))
+            .await
+            .unwrap();
+
+        assert_eq!(value, Value::BulkString(b"value".to_vec()));
+        assert_eq!(fetch_count.load(Ordering::SeqCst), 2);
+        assert!(passwords.lock().unwrap().contains(&"token-2".to_string()));
+    }
+
+    #[cfg(all(feature = "token-based-authentication", feature = "tokio-comp"))]
+    #[tokio::test]
+    async fn test_connections_share_one_credentials_provider() {
+        use crate::auth::{BasicAuth, PollingStreamingProvider};
+        use crate::auth_management::RetryConfig;
+        use futures_util::StreamExt;
+        use std::sync::Mutex;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::AsyncWriteExt;
+        use tokio_util::codec::FramedRead;
+
+        // Records the passwords the client authenticates with, and accepts every command.
+        fn spawn_server(server_half: tokio::io::DuplexStream) -> Arc<Mutex<Vec<String>>> {
+            let passwords = Arc::new(Mutex::new(Vec::new()));
+            let seen_passwords = passwords.clone();
+            let (server_read, mut server_write) = tokio::io::split(server_half);
+            tokio::spawn(async move {
+                let mut reader = FramedRead::new(server_read, ValueCodec::default());
+                while let Some(Ok(Value::Array(args))) = reader.next().await {
+                    let args: Vec<String> = args
+                        .into_iter()
+                        .map(|arg| crate::from_redis_value(arg).unwrap())
+                        .collect();
+                    if args[0] == "AUTH" {
+                        seen_passwords
+                            .lock()
+                            .unwrap()
+                            .push(args.last().unwrap().clone());
+                    }
+                    server_write.write_all(b"+OK\r\n").await.unwrap();
+                }
+            });
+            passwords
+        }
+
+        let fetch_count = Arc::new(AtomicUsize::new(0));
+        let fetch_count_ref = fetch_count.clone();
+        let provider: Arc<dyn StreamingCredentialsProvider> =
+            Arc::new(PollingStreamingProvider::new(
+                Duration::from_secs(3600),
+                RetryConfig::default(),
+                move || {
+                    let fetch = fetch_count_ref.fetch_add(1, Ordering::SeqCst) + 1;
+                    async move { Ok(BasicAuth::new("user".to_string(), format!("token-{fetch}"))) }
+                },
+            ));
+
+        let mut connections = Vec::new();
+        let mut server_passwords = Vec::new();
+        for _ in 0..2 {
+            let (client_half, server_half) = tokio::io::duplex(4096);
+            server_passwords.push(spawn_server(server_half));
+            let config = AsyncConnectionConfig::new()
+                .set_shared_credentials_provider(provider.clone())
+                .set_connection_timeout(None);
+            let (conn, driver) =
+                MultiplexedConnection::new_with_config(&mock_conn_info(), client_half, config)
+                    .await
+                    .unwrap();
+            tokio::spawn(driver);
+            connections.push(conn);
+        }
+
+        for conn in &mut connections {
+            let value = conn.send_packed_command(&cmd("PING")).await.unwrap();
+            assert_eq!(value, Value::Okay);
+        }
+        // Both connections authenticated with the credentials of a single fetch
+        assert_eq!(fetch_count.load(Ordering::SeqCst), 1);
+        for passwords in server_passwords {
+            assert!(!passwords.lock().unwrap().is_empty());
+            assert!(passwords.lock().unwrap().iter().all(|p| p == "token-1"));
+        }
+    }
 }
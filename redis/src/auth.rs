@@ -4,10 +4,11 @@ use futures_util::Stream;
 use std::pin::Pin;
 
 /// Basic authentication credentials for a Redis connection
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub struct BasicAuth {
-    /// The username for authentication
-    pub(crate) username: String,
+    /// The username for authentication.
+    /// When absent, connections authenticate using the legacy `AUTH <password>` form.
+    pub(crate) username: Option<String>,
     /// The password for authentication
     pub(crate) password: String,
 }
@@ -15,12 +16,34 @@ pub struct BasicAuth {
 impl BasicAuth {
     /// Create new BasicAuth credentials
     pub fn new(username: String, password: String) -> Self {
-        Self { username, password }
+        Self {
+            username: Some(username),
+            password,
+        }
+    }
+
+    /// Create new BasicAuth credentials that carry only a password
+    ///
+    /// Connections authenticated with such credentials send `AUTH <password>`,
+    /// which authenticates as the `default` user.
+    pub fn from_password(password: String) -> Self {
+        Self {
+            username: None,
+            password,
+        }
     }
 
     /// Get the username
+    ///
+    /// Returns an empty string for password-only credentials created with [`BasicAuth::from_password`].
+    /// Use [`BasicAuth::optional_username`] to tell those apart.
     pub fn username(&self) -> &str {
-        &self.username
+        self.username.as_deref().unwrap_or_default()
+    }
+
+    /// Get the username, or `None` for password-only credentials
+    pub fn optional_username(&self) -> Option<&str> {
+        self.username.as_deref()
     }
 
     /// Get the password
@@ -30,13 +53,29 @@ impl BasicAuth {
 
     /// Set the username
     pub fn set_username(&mut self, username: String) {
-        self.username = username;
+        self.username = Some(username);
     }
 
     /// Set the password
     pub fn set_password(&mut self, password: String) {
         self.password = password;
     }
+
+    /// Build the `AUTH` command for these credentials.
+    /// The two-argument ACL form is used only when a username is present.
+    #[cfg(feature = "aio")]
+    pub(crate) fn auth_cmd(&self) -> crate::cmd::Cmd {
+        crate::connection::authenticate_cmd(self.optional_username(), &self.password)
+    }
+}
+
+impl std::fmt::Debug for BasicAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BasicAuth")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .finish()
+    }
 }
 
 /// Trait for providing credentials in a streaming fashion
@@ -47,4 +86,525 @@ impl BasicAuth {
 pub trait StreamingCredentialsProvider: Send + Sync {
     /// Get an independent stream of credentials.
     fn subscribe(&self) -> Pin<Box<dyn Stream<Item = RedisResult<BasicAuth>> + Send + 'static>>;
+
+    /// Discard any cached credentials, because the server rejected them.
+    ///
+    /// Connections call this when a command fails with `NOAUTH` or `WRONGPASS`, before taking the next
+    /// credentials from a new [`subscribe`](Self::subscribe) stream. The default implementation does nothing.
+    fn invalidate(&self) {}
+}
+
+/// A [`StreamingCredentialsProvider`] adapter that attaches a fixed username to the credentials of another provider
+///
+/// This is useful when a provider yields only a password (e.g. a token), but the server expects
+/// the ACL form `AUTH <username> <password>`. Errors and passwords from the inner provider are passed through unchanged.
+///
+/// # Example
+/// ```rust,no_run
+/// # #[cfg(feature = "entra-id")]
+/// # fn example() -> redis::RedisResult<()> {
+/// use redis::{AsyncConnectionConfig, EntraIdCredentialsProvider, RetryConfig, WithUsername};
+///
+/// let mut provider = EntraIdCredentialsProvider::new_developer_tools()?;
+/// provider.start(RetryConfig::default());
+///
+/// let config = AsyncConnectionConfig::new()
+///     .set_credentials_provider(WithUsername::new(provider, "service-user".to_string()));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WithUsername<P> {
+    inner: P,
+    username: String,
+}
+
+impl<P: StreamingCredentialsProvider> WithUsername<P> {
+    /// Wrap `inner` so that all the credentials it yields carry `username`
+    pub fn new(inner: P, username: String) -> Self {
+        Self { inner, username }
+    }
+
+    /// Get the wrapped provider
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+}
+
+impl<P: StreamingCredentialsProvider> StreamingCredentialsProvider for WithUsername<P> {
+    fn subscribe(&self) -> Pin<Box<dyn Stream<Item = RedisResult<BasicAuth>> + Send + 'static>> {
+        use futures_util::StreamExt;
+
+        let username = self.username.clone();
+        self.inner
+            .subscribe()
+            .map(move |credentials| {
+                credentials.map(|mut credentials| {
+                    credentials.set_username(username.clone());
+                    credentials
+                })
+            })
+            .boxed()
+    }
+
+    fn invalidate(&self) {
+        self.inner.invalidate();
+    }
+}
+
+#[cfg(feature = "tokio-comp")]
+type CredentialsFetcher = dyn Fn() -> Pin<Box<dyn std::future::Future<Output = RedisResult<BasicAuth>> + Send>>
+    + Send
+    + Sync;
+
+/// A [`StreamingCredentialsProvider`] that polls a credentials source on a fixed interval
+///
+/// This turns any asynchronous credentials source (e.g. a secrets store or a token endpoint)
+/// into a streaming provider. A single background task polls the source once per `interval`
+/// and shares the result with all subscribers, so the number of connections using the provider
+/// (e.g. a connection pool or a cluster client) doesn't affect how often the source is called.
+/// The task starts with the first subscriber and stops once all subscriptions are dropped.
+/// New subscribers immediately receive the latest credentials, and a subscriber that falls behind
+/// only receives the latest result. [`invalidate`](StreamingCredentialsProvider::invalidate) discards
+/// the latest credentials and makes the task fetch new ones right away; until they arrive, new subscribers
+/// wait for them instead of receiving the discarded ones. Failed fetches are retried according to the [`RetryConfig`];
+/// once the retries are exhausted, the error is yielded to the subscribers and polling
+/// continues on the next interval.
+///
+/// # Example
+/// ```rust,no_run
+/// use redis::{AsyncConnectionConfig, BasicAuth, PollingStreamingProvider, RetryConfig};
+/// use std::time::Duration;
+///
+/// let provider = PollingStreamingProvider::new(
+///     Duration::from_secs(60),
+///     RetryConfig::default(),
+///     || async { Ok(BasicAuth::new("user".to_string(), "rotating-secret".to_string())) },
+/// );
+/// let config = AsyncConnectionConfig::new().set_credentials_provider(provider);
+/// ```
+///
+/// [`RetryConfig`]: crate::RetryConfig
+#[cfg(feature = "tokio-comp")]
+#[derive(Clone)]
+pub struct PollingStreamingProvider {
+    fetch: std::sync::Arc<CredentialsFetcher>,
+    interval: std::time::Duration,
+    retry_config: crate::auth_management::RetryConfig,
+    // The sender of the running poller, if any. Only the poller task holds a strong reference.
+    poller: std::sync::Arc<std::sync::Mutex<std::sync::Weak<PolledCredentialsSender>>>,
+    // Wakes the running poller for an immediate fetch.
+    refresh_requested: std::sync::Arc<tokio::sync::Notify>,
+}
+
+#[cfg(feature = "tokio-comp")]
+type PolledCredentialsSender = tokio::sync::watch::Sender<Option<RedisResult<BasicAuth>>>;
+
+#[cfg(feature = "tokio-comp")]
+impl PollingStreamingProvider {
+    /// Create a new provider which calls `fetch` once per `interval`
+    pub fn new<F, Fut>(
+        interval: std::time::Duration,
+        retry_config: crate::auth_management::RetryConfig,
+        fetch: F,
+    ) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = RedisResult<BasicAuth>> + Send + 'static,
+    {
+        Self {
+            fetch: std::sync::Arc::new(move || Box::pin(fetch())),
+            interval,
+            retry_config,
+            poller: Default::default(),
+            refresh_requested: Default::default(),
+        }
+    }
+
+    /// Subscribe to the running poller, starting one if there is none
+    fn subscribe_to_poller(&self) -> tokio::sync::watch::Receiver<Option<RedisResult<BasicAuth>>> {
+        let mut poller = self.poller.lock().unwrap();
+        if let Some(sender) = poller.upgrade() {
+            return sender.subscribe();
+        }
+
+        let (sender, receiver) = tokio::sync::watch::channel(None);
+        let sender = std::sync::Arc::new(sender);
+        *poller = std::sync::Arc::downgrade(&sender);
+        tokio::spawn(Self::poll(
+            std::sync::Arc::clone(&self.fetch),
+            self.interval,
+            self.retry_config.clone(),
+            std::sync::Arc::clone(&self.poller),
+            std::sync::Arc::clone(&self.refresh_requested),
+            sender,
+        ));
+        receiver
+    }
+
+    async fn poll(
+        fetch: std::sync::Arc<CredentialsFetcher>,
+        interval: std::time::Duration,
+        retry_config: crate::auth_management::RetryConfig,
+        poller: std::sync::Arc<std::sync::Mutex<std::sync::Weak<PolledCredentialsSender>>>,
+        refresh_requested: std::sync::Arc<tokio::sync::Notify>,
+        sender: std::sync::Arc<PolledCredentialsSender>,
+    ) {
+        use futures_util::future::select;
+        use std::pin::pin;
+
+        loop {
+            let credentials = Self::fetch_with_retries(fetch.as_ref(), &retry_config).await;
+            sender.send_replace(Some(credentials));
+            // An invalidation cuts the sleep short
+            select(
+                pin!(tokio::time::sleep(interval)),
+                pin!(refresh_requested.notified()),
+            )
+            .await;
+
+            // Checked under the lock, so that no new subscriber can join a poller that is stopping
+            let mut poller = poller.lock().unwrap();
+            if sender.receiver_count() == 0 {
+                *poller = std::sync::Weak::new();
+                return;
+            }
+        }
+    }
+
+    async fn fetch_with_retries(
+        fetch: &CredentialsFetcher,
+        retry_config: &crate::auth_management::RetryConfig,
+    ) -> RedisResult<BasicAuth> {
+        use crate::auth_management::credentials_management_utils;
+        use backon::Retryable;
+
+        let started_at = tokio::time::Instant::now();
+        let mut attempts = 0;
+        (|| {
+            attempts += 1;
+            fetch()
+        })
+        .retry(retry_config.backoff_builder())
+        .sleep(tokio::time::sleep)
+        .adjust(|_, delay| retry_config.delay_within_budget(started_at.elapsed(), delay))
+        .await
+        .map_err(|err| {
+            credentials_management_utils::refresh_attempts_exhausted_error(
+                "Credentials refresh failed",
+                attempts,
+                err,
+            )
+        })
+    }
+}
+
+#[cfg(feature = "tokio-comp")]
+impl StreamingCredentialsProvider for PollingStreamingProvider {
+    fn subscribe(&self) -> Pin<Box<dyn Stream<Item = RedisResult<BasicAuth>> + Send + 'static>> {
+        use futures_util::StreamExt;
+
+        // The poller is only joined once the stream is polled, so that subscribing doesn't require a runtime
+        let provider = self.clone();
+        futures_util::stream::once(async move {
+            let mut receiver = provider.subscribe_to_poller();
+            // Replay the latest result, if the poller has produced one already
+            if receiver.borrow().is_some() {
+                receiver.mark_changed();
+            }
+            receiver
+        })
+        .flat_map(|receiver| {
+            futures_util::stream::unfold(receiver, |mut receiver| async move {
+                loop {
+                    receiver.changed().await.ok()?;
+                    let credentials = receiver.borrow_and_update().clone();
+                    if let Some(credentials) = credentials {
+                        return Some((credentials, receiver));
+                    }
+                }
+            })
+        })
+        .boxed()
+    }
+
+    fn invalidate(&self) {
+        let poller = self.poller.lock().unwrap();
+        // Without a running poller there is nothing cached, and the next subscriber starts one that fetches right away
+        if let Some(sender) = poller.upgrade() {
+            sender.send_replace(None);
+            self.refresh_requested.notify_one();
+        }
+    }
+}
+
+#[cfg(feature = "tokio-comp")]
+impl std::fmt::Debug for PollingStreamingProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PollingStreamingProvider")
+            .field("interval", &self.interval)
+            .field("retry_config", &self.retry_config)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BasicAuth;
+
+    #[cfg(feature = "aio")]
+    #[test]
+    fn test_auth_cmd_with_username() {
+        let credentials = BasicAuth::new("alice".to_string(), "secret".to_string());
+        assert_eq!(credentials.username(), "alice");
+        assert_eq!(credentials.optional_username(), Some("alice"));
+        assert_eq!(
+            "*3\r\n$4\r\nAUTH\r\n$5\r\nalice\r\n$6\r\nsecret\r\n",
+            std::str::from_utf8(&credentials.auth_cmd().get_packed_command()).unwrap()
+        );
+    }
+
+    #[cfg(feature = "aio")]
+    #[test]
+    fn test_auth_cmd_without_username() {
+        let credentials = BasicAuth::from_password("secret".to_string());
+        assert_eq!(credentials.username(), "");
+        assert_eq!(credentials.optional_username(), None);
+        assert_eq!(
+            "*2\r\n$4\r\nAUTH\r\n$6\r\nsecret\r\n",
+            std::str::from_utf8(&credentials.auth_cmd().get_packed_command()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_with_username_attaches_username() {
+        use super::{StreamingCredentialsProvider, WithUsername};
+        use crate::{ErrorKind, RedisError, RedisResult};
+        use futures_util::{Stream, StreamExt};
+        use std::pin::Pin;
+
+        struct TokenProvider;
+
+        impl StreamingCredentialsProvider for TokenProvider {
+            fn subscribe(
+                &self,
+            ) -> Pin<Box<dyn Stream<Item = RedisResult<BasicAuth>> + Send + 'static>> {
+                futures_util::stream::iter([
+                    Ok(BasicAuth::from_password("token-1".to_string())),
+                    Ok(BasicAuth::new("other".to_string(), "token-2".to_string())),
+                    Err(RedisError::from((
+                        ErrorKind::AuthenticationFailed,
+                        "Expired",
+                    ))),
+                ])
+                .boxed()
+            }
+        }
+
+        let provider = WithUsername::new(TokenProvider, "alice".to_string());
+        let results: Vec<_> = futures::executor::block_on(provider.subscribe().collect());
+
+        let credentials = results[0].as_ref().unwrap();
+        assert_eq!(credentials.username(), "alice");
+        assert_eq!(credentials.password(), "token-1");
+
+        let credentials = results[1].as_ref().unwrap();
+        assert_eq!(credentials.username(), "alice");
+        assert_eq!(credentials.password(), "token-2");
+
+        assert_eq!(
+            results[2].as_ref().unwrap_err().kind(),
+            ErrorKind::AuthenticationFailed
+        );
+    }
+
+    #[test]
+    fn test_debug_redacts_password() {
+        let credentials = BasicAuth::new("alice".to_string(), "secret".to_string());
+        let debug = format!("{credentials:?}");
+        assert!(debug.contains("alice"));
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains("secret"));
+    }
+}
+
+#[cfg(all(feature = "tokio-comp", test))]
+mod polling_tests {
+    use super::{PollingStreamingProvider, StreamingCredentialsProvider};
+    use crate::{BasicAuth, ErrorKind, RedisError, RetryConfig};
+    use futures_util::StreamExt;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    const POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+    #[tokio::test(start_paused = true)]
+    async fn test_polling_provider_yields_rotated_credentials() {
+        let call_count = Arc::new(AtomicUsize::new(0));
+        let call_count_ref = Arc::clone(&call_count);
+        let provider =
+            PollingStreamingProvider::new(POLL_INTERVAL, RetryConfig::default(), move || {
+                let call = call_count_ref.fetch_add(1, Ordering::SeqCst) + 1;
+                async move { Ok(BasicAuth::new("user".to_string(), format!("token-{call}"))) }
+            });
+
+        let mut stream = provider.subscribe();
+        let start = tokio::time::Instant::now();
+
+        let credentials = stream.next().await.unwrap().unwrap();
+        assert_eq!(credentials.password(), "token-1");
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        let credentials = stream.next().await.unwrap().unwrap();
+        assert_eq!(credentials.password(), "token-2");
+        assert_eq!(start.elapsed(), POLL_INTERVAL);
+
+        let credentials = stream.next().await.unwrap().unwrap();
+        assert_eq!(credentials.password(), "token-3");
+        assert_eq!(start.elapsed(), POLL_INTERVAL * 2);
+        assert_eq!(call_count.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_polling_provider_shares_one_poller() {
+        let call_count = Arc::new(AtomicUsize::new(0));
+        let call_count_ref = Arc::clone(&call_count);
+        let provider =
+            PollingStreamingProvider::new(POLL_INTERVAL, RetryConfig::default(), move || {
+                let call = call_count_ref.fetch_add(1, Ordering::SeqCst) + 1;
+                async move { Ok(BasicAuth::new("user".to_string(), format!("token-{call}"))) }
+            });
+
+        let mut streams: Vec<_> = (0..3).map(|_| provider.subscribe()).collect();
+        for stream in &mut streams {
+            assert_eq!(stream.next().await.unwrap().unwrap().password(), "token-1");
+        }
+        for stream in &mut streams {
+            assert_eq!(stream.next().await.unwrap().unwrap().password(), "token-2");
+        }
+        assert_eq!(call_count.load(Ordering::SeqCst), 2);
+
+        // A late subscriber gets the latest credentials without another fetch
+        let mut late_stream = provider.clone().subscribe();
+        assert_eq!(
+            late_stream.next().await.unwrap().unwrap().password(),
+            "token-2"
+        );
+        assert_eq!(call_count.load(Ordering::SeqCst), 2);
+
+        // Once every subscription is dropped, the poller stops and a new subscriber starts another one
+        drop(streams);
+        drop(late_stream);
+        tokio::time::sleep(POLL_INTERVAL * 2).await;
+        let calls_after_stop = call_count.load(Ordering::SeqCst);
+        tokio::time::sleep(POLL_INTERVAL * 2).await;
+        assert_eq!(call_count.load(Ordering::SeqCst), calls_after_stop);
+        let credentials = provider.subscribe().next().await.unwrap().unwrap();
+        assert_eq!(
+            credentials.password(),
+            format!("token-{}", calls_after_stop + 1)
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_polling_provider_invalidate_fetches_right_away() {
+        let call_count = Arc::new(AtomicUsize::new(0));
+        let call_count_ref = Arc::clone(&call_count);
+        let provider =
+            PollingStreamingProvider::new(POLL_INTERVAL, RetryConfig::default(), move || {
+                let call = call_count_ref.fetch_add(1, Ordering::SeqCst) + 1;
+                async move { Ok(BasicAuth::new("user".to_string(), format!("token-{call}"))) }
+            });
+
+        let mut stream = provider.subscribe();
+        assert_eq!(stream.next().await.unwrap().unwrap().password(), "token-1");
+        let start = tokio::time::Instant::now();
+
+        // A new subscriber waits for the fresh credentials instead of replaying the invalidated ones
+        provider.invalidate();
+        let credentials = provider.subscribe().next().await.unwrap().unwrap();
+        assert_eq!(credentials.password(), "token-2");
+        assert_eq!(stream.next().await.unwrap().unwrap().password(), "token-2");
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        assert_eq!(call_count.load(Ordering::SeqCst), 2);
+
+        // The regular polling resumes afterwards
+        assert_eq!(stream.next().await.unwrap().unwrap().password(), "token-3");
+        assert_eq!(start.elapsed(), POLL_INTERVAL);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_polling_provider_retries_failed_fetches() {
+        let call_count = Arc::new(AtomicUsize::new(0));
+        let call_count_ref = Arc::clone(&call_count);
+        let provider =
+            PollingStreamingProvider::new(POLL_INTERVAL, RetryConfig::default(), move || {
+                let call = call_count_ref.fetch_add(1, Ordering::SeqCst) + 1;
+                async move {
+                    if call == 1 {
+                        Err(RedisError::from((
+                            ErrorKind::AuthenticationFailed,
+                            "Temporary failure",
+                        )))
+                    } else {
+                        Ok(BasicAuth::new("user".to_string(), format!("token-{call}")))
+                    }
+                }
+            });
+
+        let credentials = provider.subscribe().next().await.unwrap().unwrap();
+        assert_eq!(credentials.password(), "token-2");
+        assert_eq!(call_count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_polling_provider_respects_max_elapsed_time() {
+        const MAX_ELAPSED_TIME: Duration = Duration::from_secs(5);
+        const ATTEMPT_DURATION: Duration = Duration::from_secs(1);
+        let call_count = Arc::new(AtomicUsize::new(0));
+        let call_count_ref = Arc::clone(&call_count);
+        let provider = PollingStreamingProvider::new(
+            POLL_INTERVAL,
+            RetryConfig::default()
+                .set_number_of_retries(100)
+                .set_max_elapsed_time(MAX_ELAPSED_TIME),
+            move || {
+                call_count_ref.fetch_add(1, Ordering::SeqCst);
+                async {
+                    tokio::time::sleep(ATTEMPT_DURATION).await;
+                    Err(RedisError::from((
+                        ErrorKind::AuthenticationFailed,
+                        "Slow failure",
+                    )))
+                }
+            },
+        );
+
+        let start = tokio::time::Instant::now();
+        let result = provider.subscribe().next().await.unwrap();
+        assert!(result.is_err());
+        // The attempt in flight when the budget runs out is allowed to finish.
+        assert!(start.elapsed() <= MAX_ELAPSED_TIME + ATTEMPT_DURATION);
+        assert!(call_count.load(Ordering::SeqCst) < 100);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_polling_provider_reports_attempts_once_retries_are_exhausted() {
+        let provider = PollingStreamingProvider::new(
+            POLL_INTERVAL,
+            RetryConfig::default().set_number_of_retries(4),
+            || async {
+                Err(RedisError::from((
+                    ErrorKind::AuthenticationFailed,
+                    "Permanent failure",
+                )))
+            },
+        );
+
+        let error = provider.subscribe().next().await.unwrap().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::AuthenticationFailed);
+        assert!(error.to_string().contains("after 5 attempt(s)"));
+        assert!(error.to_string().contains("Permanent failure"));
+    }
 }
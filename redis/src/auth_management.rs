@@ -1,5 +1,5 @@
 //! This module contains utilities for managing token-based authentication
-use std::time::{Duration, SystemTime};
+use std::time::Duration;
 
 /// Configuration for token refresh behavior
 #[derive(Debug, Clone)]
@@ -10,6 +10,9 @@ pub struct TokenRefreshConfig {
     pub expiration_refresh_ratio: f64,
     /// Retry configuration for failed refresh attempts
     pub retry_config: RetryConfig,
+    /// Lower bound on the delay between two successful refreshes.
+    /// This prevents hammering the identity provider when it returns tokens that are about to expire.
+    pub min_refresh_interval: Duration,
 }
 
 impl TokenRefreshConfig {
@@ -24,6 +27,12 @@ impl TokenRefreshConfig {
         self.retry_config = retry_config;
         self
     }
+
+    /// Set the lower bound on the delay between two successful refreshes
+    pub fn set_min_refresh_interval(mut self, min_refresh_interval: Duration) -> Self {
+        self.min_refresh_interval = min_refresh_interval;
+        self
+    }
 }
 
 impl Default for TokenRefreshConfig {
@@ -31,10 +40,23 @@ impl Default for TokenRefreshConfig {
         Self {
             expiration_refresh_ratio: 0.8,
             retry_config: RetryConfig::default(),
+            min_refresh_interval: Duration::from_secs(30),
         }
     }
 }
 
+/// Information about a completed credentials refresh, including all of its retries
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RefreshEvent {
+    /// Whether new credentials were obtained
+    pub succeeded: bool,
+    /// The time spent refreshing, including the delays between retries
+    pub duration: Duration,
+    /// The number of attempts made
+    pub attempts: usize,
+}
+
 /// Configuration for handling failed token refresh attempts
 #[derive(Debug, Clone)]
 #[non_exhaustive]
@@ -48,6 +70,10 @@ pub struct RetryConfig {
     pub(crate) max_delay: Option<Duration>,
     /// number_of_retries times, with an exponentially increasing delay
     pub(crate) number_of_retries: usize,
+    /// Upper bound on the total time spent retrying, regardless of the remaining number of retries
+    pub(crate) max_elapsed_time: Option<Duration>,
+    /// Whether random jitter is added to the delays
+    pub(crate) jitter: bool,
 }
 
 impl Default for RetryConfig {
@@ -57,11 +83,20 @@ impl Default for RetryConfig {
             min_delay: Duration::from_millis(100),
             max_delay: Some(Duration::from_secs(30)),
             exponent_base: 2.0,
+            max_elapsed_time: None,
+            jitter: true,
         }
     }
 }
 
 impl RetryConfig {
+    /// Creates the default configuration without jitter, so that retries happen at exact, predictable delays.
+    ///
+    /// This is mainly useful for testing. In production, jitter helps to avoid many clients retrying in lockstep.
+    pub fn deterministic() -> Self {
+        Self::default().set_jitter(false)
+    }
+
     /// Sets the maximum number of retry attempts for token refresh
     pub fn set_number_of_retries(mut self, number_of_retries: usize) -> Self {
         self.number_of_retries = number_of_retries;
@@ -85,23 +120,102 @@ impl RetryConfig {
         self.exponent_base = exponent_base;
         self
     }
+
+    /// Sets the upper bound on the total time spent retrying.
+    ///
+    /// Retrying stops with the last error as soon as waiting for the next attempt would exceed this budget,
+    /// even if not all retries have been used. An attempt that is already in progress is not interrupted.
+    pub fn set_max_elapsed_time(mut self, max_elapsed_time: Duration) -> Self {
+        self.max_elapsed_time = Some(max_elapsed_time);
+        self
+    }
+
+    /// Sets whether random jitter is added to the delays between attempts
+    ///
+    /// The jitter is a random amount of up to the delay itself, added after the delay is capped by the maximal delay.
+    /// A jittered delay can therefore be up to twice the maximal delay, and may be shorter than the previous one.
+    pub fn set_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Returns the given backoff delay, or `None` if waiting for it would exceed the `max_elapsed_time` budget
+    #[cfg(feature = "tokio-comp")]
+    pub(crate) fn delay_within_budget(
+        &self,
+        elapsed: Duration,
+        delay: Option<Duration>,
+    ) -> Option<Duration> {
+        let delay = delay?;
+        match self.max_elapsed_time {
+            Some(max_elapsed_time) if elapsed.saturating_add(delay) > max_elapsed_time => None,
+            _ => Some(delay),
+        }
+    }
+
+    /// Build the exponential backoff strategy described by this configuration
+    ///
+    /// Exponent bases below 1 (or NaN) are treated as 1, and the minimal delay is capped by the maximal one,
+    /// since backon doesn't cap the first delay. Without jitter, this keeps the delays from ever decreasing.
+    /// Jitter is added after the cap, so jittered delays may exceed the maximal delay and need not increase.
+    #[cfg(feature = "tokio-comp")]
+    pub(crate) fn backoff_builder(&self) -> backon::ExponentialBuilder {
+        let factor = if self.exponent_base >= 1.0 {
+            self.exponent_base
+        } else {
+            1.0
+        };
+        let min_delay = match self.max_delay {
+            Some(max_delay) => self.min_delay.min(max_delay),
+            None => self.min_delay,
+        };
+        let mut strategy = backon::ExponentialBuilder::default()
+            .with_factor(factor)
+            .with_min_delay(min_delay)
+            .with_max_times(self.number_of_retries);
+
+        if self.jitter {
+            strategy = strategy.with_jitter();
+        }
+        if let Some(max_delay) = self.max_delay {
+            strategy = strategy.with_max_delay(max_delay);
+        }
+        strategy
+    }
 }
 
 /// Common logic for credentials management
 pub(crate) mod credentials_management_utils {
-    use super::*;
+    #[cfg(feature = "tokio-comp")]
+    use crate::errors::{ErrorKind, RedisError};
+
+    /// Build the error reported once all attempts to refresh the credentials have failed.
+    /// It keeps the number of attempts made along with the cause of the last failure.
+    #[cfg(feature = "tokio-comp")]
+    pub(crate) fn refresh_attempts_exhausted_error(
+        description: &'static str,
+        attempts: usize,
+        cause: impl std::fmt::Display,
+    ) -> RedisError {
+        RedisError::from((
+            ErrorKind::AuthenticationFailed,
+            description,
+            format!("Giving up after {attempts} attempt(s): {cause}"),
+        ))
+    }
 
     /// Calculate the refresh threshold based on the token's lifetime and the refresh ratio
-    #[allow(dead_code)] // Reserved for future use with TokenRefreshConfig
+    ///
+    /// Returns `None` if the token has already expired or the ratio yields no valid duration.
+    #[cfg(feature = "entra-id")]
     pub(crate) fn calculate_refresh_threshold(
-        received_at: SystemTime,
-        expires_at: SystemTime,
+        received_at: std::time::SystemTime,
+        expires_at: std::time::SystemTime,
         refresh_ratio: f64,
-    ) -> Option<Duration> {
+    ) -> Option<std::time::Duration> {
         if let Ok(total_lifetime) = expires_at.duration_since(received_at) {
-            Some(Duration::from_secs_f64(
-                total_lifetime.as_secs_f64() * refresh_ratio,
-            ))
+            std::time::Duration::try_from_secs_f64(total_lifetime.as_secs_f64() * refresh_ratio)
+                .ok()
         } else {
             None
         }
@@ -141,7 +255,9 @@ pub(crate) mod credentials_management_utils {
 
 #[cfg(all(feature = "token-based-authentication", test))]
 mod auth_management_tests {
-    use super::{TokenRefreshConfig, credentials_management_utils};
+    use super::TokenRefreshConfig;
+    #[cfg(feature = "entra-id")]
+    use super::credentials_management_utils;
     use std::sync::LazyLock;
 
     const TOKEN_HEADER: &str = "header";
@@ -164,10 +280,22 @@ mod auth_management_tests {
         let config = TokenRefreshConfig::default();
         assert_eq!(config.expiration_refresh_ratio, 0.8);
 
-        let custom_config = TokenRefreshConfig::default().set_expiration_refresh_ratio(0.9);
+        assert_eq!(
+            config.min_refresh_interval,
+            std::time::Duration::from_secs(30)
+        );
+
+        let custom_config = TokenRefreshConfig::default()
+            .set_expiration_refresh_ratio(0.9)
+            .set_min_refresh_interval(std::time::Duration::from_secs(5));
         assert_eq!(custom_config.expiration_refresh_ratio, 0.9);
+        assert_eq!(
+            custom_config.min_refresh_interval,
+            std::time::Duration::from_secs(5)
+        );
     }
 
+    #[cfg(feature = "entra-id")]
     #[test]
     fn test_refresh_threshold_calculation() {
         use std::time::{Duration, SystemTime};
@@ -185,6 +313,106 @@ mod auth_management_tests {
         assert_eq!(threshold.unwrap(), Duration::from_secs(2880)); // 80% of 3600
     }
 
+    #[cfg(feature = "tokio-comp")]
+    #[test]
+    fn test_deterministic_backoff_sequence() {
+        use super::RetryConfig;
+        use backon::BackoffBuilder;
+        use std::time::Duration;
+
+        // The backoff factor is an f32, so compare at millisecond precision
+        let delays_in_ms = |config: RetryConfig| -> Vec<u128> {
+            config
+                .backoff_builder()
+                .build()
+                .map(|delay| delay.as_millis())
+                .collect()
+        };
+
+        assert_eq!(delays_in_ms(RetryConfig::deterministic()), [100, 200, 400]);
+        assert_eq!(
+            delays_in_ms(
+                RetryConfig::deterministic()
+                    .set_number_of_retries(5)
+                    .set_max_delay(Duration::from_millis(500))
+            ),
+            [100, 200, 400, 500, 500]
+        );
+    }
+
+    #[cfg(feature = "tokio-comp")]
+    #[test]
+    fn test_backoff_delays_never_decrease() {
+        use super::RetryConfig;
+        use backon::BackoffBuilder;
+        use std::time::Duration;
+
+        let delays = |config: RetryConfig| -> Vec<Duration> {
+            config
+                .set_number_of_retries(6)
+                .backoff_builder()
+                .build()
+                .collect()
+        };
+        let max_delay = Duration::from_secs(30);
+
+        // A pathological exponent base jumps straight to the cap and stays there
+        let capped = delays(
+            RetryConfig::deterministic()
+                .set_exponent_base(f32::MAX)
+                .set_max_delay(max_delay),
+        );
+        assert_eq!(capped[0], Duration::from_millis(100));
+        assert!(capped[1..].iter().all(|&delay| delay == max_delay));
+
+        // Without a cap, the delays saturate instead of overflowing
+        let uncapped = delays(RetryConfig {
+            max_delay: None,
+            ..RetryConfig::deterministic().set_exponent_base(f32::MAX)
+        });
+        assert!(uncapped.is_sorted());
+
+        // A minimal delay above the cap is clamped to the cap
+        let clamped = delays(
+            RetryConfig::deterministic()
+                .set_min_delay(Duration::from_secs(60))
+                .set_max_delay(max_delay),
+        );
+        assert!(clamped.iter().all(|&delay| delay == max_delay));
+
+        // Exponent bases that would shrink the delays keep them constant
+        // (compared at millisecond precision, since backon multiplies in f32)
+        for exponent_base in [0.5, 0.0, -2.0, f32::NAN] {
+            let constant = delays(RetryConfig::deterministic().set_exponent_base(exponent_base));
+            assert!(constant.iter().all(|delay| delay.as_millis() == 100));
+        }
+    }
+
+    #[cfg(feature = "tokio-comp")]
+    #[test]
+    fn test_jittered_delays_are_bounded_by_twice_the_capped_delay() {
+        use super::RetryConfig;
+        use backon::BackoffBuilder;
+        use std::time::Duration;
+
+        let config = RetryConfig::default()
+            .set_number_of_retries(10)
+            .set_max_delay(Duration::from_secs(1));
+        let deterministic: Vec<Duration> = config
+            .clone()
+            .set_jitter(false)
+            .backoff_builder()
+            .build()
+            .collect();
+        for _ in 0..20 {
+            let jittered: Vec<Duration> = config.backoff_builder().build().collect();
+            for (jittered, deterministic) in jittered.iter().zip(&deterministic) {
+                assert!(jittered.as_millis() >= deterministic.as_millis());
+                assert!(jittered.as_millis() <= 2 * deterministic.as_millis());
+            }
+        }
+    }
+
     #[cfg(all(feature = "token-based-authentication", feature = "entra-id"))]
     #[test]
     fn test_extract_oid_from_jwt() {
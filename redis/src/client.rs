@@ -180,6 +180,8 @@ pub(crate) enum Cache {
 pub(crate) const DEFAULT_RESPONSE_TIMEOUT: Option<Duration> = Some(Duration::from_millis(500));
 #[cfg(any(feature = "aio", feature = "cluster"))]
 pub(crate) const DEFAULT_CONNECTION_TIMEOUT: Option<Duration> = Some(Duration::from_secs(1));
+#[cfg(all(feature = "aio", feature = "token-based-authentication"))]
+pub(crate) const DEFAULT_CREDENTIALS_REFRESH_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// Options for creation of async connection
 #[cfg(feature = "aio")]
@@ -200,6 +202,9 @@ pub struct AsyncConnectionConfig {
     /// Optional credentials provider for dynamic authentication (e.g., token-based authentication)
     #[cfg(feature = "token-based-authentication")]
     pub(crate) credentials_provider: Option<std::sync::Arc<dyn StreamingCredentialsProvider>>,
+    /// Maximum time to wait for new credentials after the server rejected the current ones
+    #[cfg(feature = "token-based-authentication")]
+    pub(crate) credentials_refresh_timeout: Duration,
 }
 
 #[cfg(feature = "aio")]
@@ -217,6 +222,8 @@ impl Default for AsyncConnectionConfig {
             write_backpressure_boundary: None,
             #[cfg(feature = "token-based-authentication")]
             credentials_provider: None,
+            #[cfg(feature = "token-based-authentication")]
+            credentials_refresh_timeout: DEFAULT_CREDENTIALS_REFRESH_TIMEOUT,
         }
     }
 }
@@ -401,17 +408,35 @@ impl AsyncConnectionConfig {
     where
         P: StreamingCredentialsProvider + 'static,
     {
-        self.set_credentials_provider_internal(std::sync::Arc::new(provider))
+        self.set_shared_credentials_provider(std::sync::Arc::new(provider))
     }
 
+    /// Sets a credentials provider that is shared with other connections.
+    ///
+    /// Unlike [`Self::set_credentials_provider`], this takes a provider that is already behind an `Arc`,
+    /// so a single provider (and its background refresh task) can serve several clients and configurations.
     #[cfg(feature = "token-based-authentication")]
-    pub(crate) fn set_credentials_provider_internal(
+    pub fn set_shared_credentials_provider(
         mut self,
         provider: std::sync::Arc<dyn StreamingCredentialsProvider>,
     ) -> Self {
         self.credentials_provider = Some(provider);
         self
     }
+
+    /// Sets how long the connection waits for new credentials from its credentials provider
+    /// after the server rejects the current ones with `NOAUTH` or `WRONGPASS`.
+    ///
+    /// Fetching a token may involve a request to an identity service, so this is separate from the response timeout.
+    /// The default is 10 seconds.
+    ///
+    /// Only single commands are retried after such a rejection. Pipelines return the server's error,
+    /// and the connection is re-authenticated once its credentials provider yields new credentials.
+    #[cfg(feature = "token-based-authentication")]
+    pub fn set_credentials_refresh_timeout(mut self, timeout: Duration) -> Self {
+        self.credentials_refresh_timeout = timeout;
+        self
+    }
 }
 
 /// To enable async support you need to chose one of the supported runtimes and active its
@@ -731,4 +756,37 @@ mod test {
         let config = AsyncConnectionConfig::new().set_write_backpressure_boundary(16 * 1024 * 1024);
         assert_eq!(config.write_backpressure_boundary, Some(16 * 1024 * 1024));
     }
+
+    #[cfg(all(feature = "aio", feature = "token-based-authentication"))]
+    #[test]
+    fn test_async_connection_config_shared_credentials_provider() {
+        use crate::auth::BasicAuth;
+        use futures_util::Stream;
+        use std::pin::Pin;
+        use std::sync::Arc;
+
+        struct StaticProvider;
+
+        impl StreamingCredentialsProvider for StaticProvider {
+            fn subscribe(
+                &self,
+            ) -> Pin<Box<dyn Stream<Item = RedisResult<BasicAuth>> + Send + 'static>> {
+                Box::pin(futures_util::stream::empty())
+            }
+        }
+
+        let provider: Arc<dyn StreamingCredentialsProvider> = Arc::new(StaticProvider);
+        let first = AsyncConnectionConfig::new().set_shared_credentials_provider(provider.clone());
+        let second = AsyncConnectionConfig::new().set_shared_credentials_provider(provider.clone());
+
+        assert!(Arc::ptr_eq(
+            first.credentials_provider.as_ref().unwrap(),
+            &provider
+        ));
+        assert!(Arc::ptr_eq(
+            second.credentials_provider.as_ref().unwrap(),
+            &provider
+        ));
+        assert_eq!(Arc::strong_count(&provider), 3);
+    }
 }
@@ -1875,7 +1875,11 @@ where
     }
     #[cfg(feature = "token-based-authentication")]
     if let Some(credentials_provider) = &params.credentials_provider {
-        config = config.set_credentials_provider_internal(credentials_provider.clone());
+        config = config.set_shared_credentials_provider(credentials_provider.clone());
+    }
+    #[cfg(feature = "token-based-authentication")]
+    {
+        config = config.set_credentials_refresh_timeout(params.credentials_refresh_timeout);
     }
     if let Some(limit) = params.connection_concurrency_limit {
         config = config.set_concurrency_limit(limit);
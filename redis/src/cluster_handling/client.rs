@@ -5,6 +5,8 @@ use crate::auth::StreamingCredentialsProvider;
 #[cfg(all(feature = "cache-aio", feature = "cluster-async"))]
 use crate::caching::{CacheConfig, CacheManager};
 use crate::client::DEFAULT_CONNECTION_TIMEOUT;
+#[cfg(all(feature = "token-based-authentication", feature = "cluster-async"))]
+use crate::client::DEFAULT_CREDENTIALS_REFRESH_TIMEOUT;
 use crate::cluster_handling::NodeAddress;
 use crate::cluster_handling::read_routing::{RandomReplicaStrategy, ReadRoutingStrategyFactory};
 use crate::connection::{ConnectionAddr, ConnectionInfo, IntoConnectionInfo};
@@ -67,6 +69,8 @@ struct BuilderParams {
     cache_config: Option<CacheConfig>,
     #[cfg(all(feature = "token-based-authentication", feature = "cluster-async"))]
     credentials_provider: Option<std::sync::Arc<dyn StreamingCredentialsProvider>>,
+    #[cfg(all(feature = "token-based-authentication", feature = "cluster-async"))]
+    credentials_refresh_timeout: Option<Duration>,
     #[cfg(feature = "cluster-async")]
     overall_response_timeout: OverallResponseTimeout,
     #[cfg(feature = "cluster-async")]
@@ -156,6 +160,8 @@ pub(crate) struct ClusterParams {
     pub(crate) cache_manager: Option<CacheManager>,
     #[cfg(all(feature = "token-based-authentication", feature = "cluster-async"))]
     pub(crate) credentials_provider: Option<std::sync::Arc<dyn StreamingCredentialsProvider>>,
+    #[cfg(all(feature = "token-based-authentication", feature = "cluster-async"))]
+    pub(crate) credentials_refresh_timeout: Duration,
     #[cfg(feature = "cluster-async")]
     pub(crate) overall_response_timeout: Option<Duration>,
     #[cfg(feature = "cluster-async")]
@@ -220,6 +226,10 @@ impl ClusterParams {
             cache_manager,
             #[cfg(all(feature = "token-based-authentication", feature = "cluster-async"))]
             credentials_provider: value.credentials_provider,
+            #[cfg(all(feature = "token-based-authentication", feature = "cluster-async"))]
+            credentials_refresh_timeout: value
+                .credentials_refresh_timeout
+                .unwrap_or(DEFAULT_CREDENTIALS_REFRESH_TIMEOUT),
             #[cfg(feature = "cluster-async")]
             overall_response_timeout: match value.overall_response_timeout {
                 OverallResponseTimeout::MatchResponseTimeout => value.response_timeout,
@@ -719,11 +729,31 @@ impl ClusterClientBuilder {
     /// Each node connection will independently subscribe to the provider and automatically
     /// re-authenticate when new credentials are emitted.
     #[cfg(all(feature = "token-based-authentication", feature = "cluster-async"))]
-    pub fn set_credentials_provider<P>(mut self, provider: P) -> ClusterClientBuilder
+    pub fn set_credentials_provider<P>(self, provider: P) -> ClusterClientBuilder
     where
         P: StreamingCredentialsProvider + 'static,
     {
-        self.builder_params.credentials_provider = Some(std::sync::Arc::new(provider));
+        self.set_shared_credentials_provider(std::sync::Arc::new(provider))
+    }
+
+    /// Sets a credentials provider that is shared with other clients on all cluster node connections.
+    ///
+    /// See [`crate::AsyncConnectionConfig::set_shared_credentials_provider`].
+    #[cfg(all(feature = "token-based-authentication", feature = "cluster-async"))]
+    pub fn set_shared_credentials_provider(
+        mut self,
+        provider: std::sync::Arc<dyn StreamingCredentialsProvider>,
+    ) -> ClusterClientBuilder {
+        self.builder_params.credentials_provider = Some(provider);
+        self
+    }
+
+    /// Sets how long node connections wait for new credentials after the server rejects the current ones.
+    ///
+    /// See [`crate::AsyncConnectionConfig::set_credentials_refresh_timeout`].
+    #[cfg(all(feature = "token-based-authentication", feature = "cluster-async"))]
+    pub fn set_credentials_refresh_timeout(mut self, timeout: Duration) -> ClusterClientBuilder {
+        self.builder_params.credentials_refresh_timeout = Some(timeout);
         self
     }
 }
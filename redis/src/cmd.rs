@@ -80,7 +80,7 @@ pub struct Cmd {
     pub(crate) data: Vec<u8>,
     // Arg::Simple contains the offset that marks the end of the argument
     args: Vec<Arg<usize>>,
-    cursor: Option<u64>,
+    pub(crate) cursor: Option<u64>,
     // If it's true command's response won't be read from socket. Useful for Pub/Sub.
     no_response: bool,
     pub(crate) skip_concurrency_limit: bool,
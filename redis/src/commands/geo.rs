@@ -2,11 +2,14 @@
 
 use crate::errors::{ParsingError, invalid_type_error};
 use crate::types::{FromRedisValue, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+use std::fmt;
+use std::str::FromStr;
 
 /// Units used by [`geo_dist`][1] and [`geo_radius`][2].
 ///
 /// [1]: ../trait.Commands.html#method.geo_dist
 /// [2]: ../trait.Commands.html#method.geo_radius
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum Unit {
     /// Represents meters.
@@ -19,18 +22,41 @@ pub enum Unit {
     Feet,
 }
 
+impl Unit {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            Unit::Meters => "m",
+            Unit::Kilometers => "km",
+            Unit::Miles => "mi",
+            Unit::Feet => "ft",
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parses the unit names used by Redis (`m`, `km`, `mi` and `ft`), ignoring case.
+impl FromStr for Unit {
+    type Err = ParsingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [Unit::Meters, Unit::Kilometers, Unit::Miles, Unit::Feet]
+            .into_iter()
+            .find(|unit| unit.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Unknown geo unit: {s}").into())
+    }
+}
+
 impl ToRedisArgs for Unit {
     fn write_redis_args<W>(&self, out: &mut W)
     where
         W: ?Sized + RedisWrite,
     {
-        let unit = match *self {
-            Unit::Meters => "m",
-            Unit::Kilometers => "km",
-            Unit::Miles => "mi",
-            Unit::Feet => "ft",
-        };
-        out.write_arg(unit.as_bytes());
+        out.write_arg(self.as_str().as_bytes());
     }
 }
 
@@ -318,7 +344,7 @@ impl RadiusSearchResult {
 
 #[cfg(test)]
 mod tests {
-    use super::{Coord, RadiusOptions, RadiusOrder};
+    use super::{Coord, RadiusOptions, RadiusOrder, Unit};
     use crate::types::ToRedisArgs;
     use std::str;
 
@@ -338,6 +364,24 @@ mod tests {
         assert_args!(&member, "Palermo", "13.361389", "38.115556");
     }
 
+    #[test]
+    fn test_unit() {
+        for (unit, name) in [
+            (Unit::Meters, "m"),
+            (Unit::Kilometers, "km"),
+            (Unit::Miles, "mi"),
+            (Unit::Feet, "ft"),
+        ] {
+            assert_args!(unit, name);
+            assert_eq!(unit.to_string(), name);
+            assert_eq!(name.parse::<Unit>().unwrap(), unit);
+            assert_eq!(name.to_uppercase().parse::<Unit>().unwrap(), unit);
+        }
+
+        assert!("kms".parse::<Unit>().is_err());
+        assert!("".parse::<Unit>().is_err());
+    }
+
     #[test]
     fn test_radius_options() {
         // Without options, should not generate any argument
@@ -1524,6 +1524,7 @@ implement_commands! {
 
     /// Return metadata and internal details about a vector set, including
     /// size, dimensions, quantization type, and graph structure.
+    /// To parse the reply into a [`VectorSetInfo`](crate::vector_sets::VectorSetInfo), send VINFO with [`cmd`](crate::cmd).
     /// [Redis Docs](https://redis.io/commands/VINFO)
     #[cfg(feature = "vector-sets")]
     #[cfg_attr(docsrs, doc(cfg(feature = "vector-sets")))]
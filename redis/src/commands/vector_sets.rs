@@ -1,6 +1,9 @@
 //! Defines types to use with the vector sets commands.
 
+use crate::errors::ParsingError;
+use crate::types::{FromRedisValue, Value, from_redis_value};
 use crate::{RedisWrite, ToRedisArgs};
+use std::collections::HashMap;
 
 /// Options for the VSIM command
 ///
@@ -205,7 +208,7 @@ impl ToRedisArgs for VectorAddInput<'_> {
 }
 
 /// Quantization options for vector storage
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum VectorQuantization {
     /// In the first VADD call for a given key, NOQUANT forces the vector to be created without int8 quantization, which is otherwise the default.
@@ -370,6 +373,86 @@ impl ToRedisArgs for VEmbOptions {
     }
 }
 
+/// The approximate vector of an element, as returned by [`vemb`](crate::Commands::vemb).
+///
+/// The raw representation returned with [`VEmbOptions::set_raw_representation`] is not supported.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VectorEmbedding(pub Vec<f32>);
+
+impl FromRedisValue for VectorEmbedding {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let Value::Array(items) = v else {
+            crate::errors::invalid_type_error!("Expected an array of floats for VEMB", v);
+        };
+        Ok(VectorEmbedding(from_redis_value(Value::Array(items))?))
+    }
+}
+
+/// Metadata about a vector set, as returned by the VINFO command.
+///
+/// The fields which are not known to this type are kept in `other`.
+/// Since [`vinfo`](crate::Commands::vinfo) returns the raw fields, send VINFO with [`cmd`](crate::cmd)
+/// to get this type. The reply is nil if the key doesn't exist.
+///
+/// # Example
+/// ```rust,no_run
+/// use redis::{RedisResult, vector_sets::VectorSetInfo};
+/// fn get_dimension(con: &mut redis::Connection, key: &str) -> RedisResult<Option<usize>> {
+///     let info: Option<VectorSetInfo> = redis::cmd("VINFO").arg(key).query(con)?;
+///     Ok(info.and_then(|info| info.dimension))
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[non_exhaustive]
+pub struct VectorSetInfo {
+    /// The quantization used to store the vectors
+    pub quantization: Option<VectorQuantization>,
+    /// The number of dimensions of the vectors
+    pub dimension: Option<usize>,
+    /// The number of elements in the vector set
+    pub size: Option<usize>,
+    /// The number of elements that have attributes
+    pub attributes_count: Option<usize>,
+    /// All other fields of the reply
+    pub other: HashMap<String, Value>,
+}
+
+impl FromRedisValue for VectorSetInfo {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        // The reply is an Array of alternating field names and values in RESP2, and a Map in RESP3
+        let mut fields: HashMap<String, Value> = from_redis_value(v)?;
+        let mut take = |name: &str| -> Result<Option<usize>, ParsingError> {
+            fields.remove(name).map(from_redis_value).transpose()
+        };
+
+        let dimension = take("vector-dim")?;
+        let size = take("size")?;
+        let attributes_count = take("attributes-count")?;
+
+        let quantization = match fields.get("quant-type") {
+            Some(value) => match from_redis_value::<String>(value.clone())?.as_str() {
+                "f32" => Some(VectorQuantization::NoQuant),
+                "int8" => Some(VectorQuantization::Q8),
+                "bin" => Some(VectorQuantization::Bin),
+                // Unknown quantizations stay available in `other`
+                _ => None,
+            },
+            None => None,
+        };
+        if quantization.is_some() {
+            fields.remove("quant-type");
+        }
+
+        Ok(VectorSetInfo {
+            quantization,
+            dimension,
+            size,
+            attributes_count,
+            other: fields,
+        })
+    }
+}
+
 /// Represents different ways to input query data for vector similarity search commands
 #[cfg_attr(docsrs, doc(cfg(feature = "vector-sets")))]
 #[derive(Clone)]
@@ -408,3 +491,162 @@ impl ToRedisArgs for VectorSimilaritySearchInput<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        EmbeddingInput, VSimOptions, VectorEmbedding, VectorQuantization, VectorSetInfo,
+        VectorSimilaritySearchInput,
+    };
+    use crate::types::{FromRedisValue, ToRedisArgs, Value};
+    use std::str;
+
+    macro_rules! assert_args {
+        ($value:expr, $($args:expr),*) => {
+            let args = $value.to_redis_args();
+            let strings: Vec<_> = args.iter()
+                                      .map(|a| str::from_utf8(a.as_ref()).unwrap())
+                                      .collect();
+            assert_eq!(strings, Vec::<&str>::from([$($args),*]));
+        }
+    }
+
+    #[test]
+    fn test_vsim_options() {
+        // Without options, should not generate any argument
+        assert_args!(VSimOptions::default(),);
+
+        let opts = VSimOptions::default;
+
+        assert_args!(opts().set_with_scores(true), "WITHSCORES");
+        assert_args!(opts().set_with_scores(false),);
+
+        assert_args!(
+            opts().set_count(10).set_search_exploration_factor(200),
+            "COUNT",
+            "10",
+            "EF",
+            "200"
+        );
+
+        assert_args!(
+            opts()
+                .set_filter_expression(".year > 1950")
+                .set_max_filtering_effort(500),
+            "FILTER",
+            ".year > 1950",
+            "FILTER-EF",
+            "500"
+        );
+
+        // Arguments are always emitted in the same order, regardless of the order of the setters
+        assert_args!(
+            opts()
+                .set_no_thread(true)
+                .set_truth(true)
+                .set_max_filtering_effort(5)
+                .set_filter_expression(".a == 1")
+                .set_search_exploration_factor(50)
+                .set_count(3)
+                .set_with_scores(true),
+            "WITHSCORES",
+            "COUNT",
+            "3",
+            "EF",
+            "50",
+            "FILTER",
+            ".a == 1",
+            "FILTER-EF",
+            "5",
+            "TRUTH",
+            "NOTHREAD"
+        );
+    }
+
+    #[test]
+    fn test_vector_similarity_search_input() {
+        assert_args!(
+            VectorSimilaritySearchInput::Element("pizza"),
+            "ELE",
+            "pizza"
+        );
+
+        assert_args!(
+            VectorSimilaritySearchInput::Values(EmbeddingInput::Float64(&[0.5, 1.0])),
+            "VALUES",
+            "2",
+            "0.5",
+            "1"
+        );
+
+        let args = VectorSimilaritySearchInput::Fp32(&[1.0, -2.0]).to_redis_args();
+        assert_eq!(args[0], b"FP32");
+        assert_eq!(
+            args[1],
+            [1.0f32.to_le_bytes(), (-2.0f32).to_le_bytes()].concat()
+        );
+    }
+
+    #[test]
+    fn test_vector_embedding() {
+        let resp2 = Value::Array(vec![
+            Value::BulkString(b"0.5".to_vec()),
+            Value::BulkString(b"-1".to_vec()),
+        ]);
+        assert_eq!(
+            VectorEmbedding::from_redis_value(resp2).unwrap(),
+            VectorEmbedding(vec![0.5, -1.0])
+        );
+
+        let resp3 = Value::Array(vec![Value::Double(0.5), Value::Double(-1.0)]);
+        assert_eq!(
+            VectorEmbedding::from_redis_value(resp3).unwrap(),
+            VectorEmbedding(vec![0.5, -1.0])
+        );
+
+        // Missing elements are reported as nil
+        assert_eq!(
+            Option::<VectorEmbedding>::from_redis_value(Value::Nil).unwrap(),
+            None
+        );
+        assert!(VectorEmbedding::from_redis_value(Value::Int(1)).is_err());
+    }
+
+    #[test]
+    fn test_vector_set_info() {
+        let field = |name: &str| Value::SimpleString(name.to_string());
+        let fields = vec![
+            (field("quant-type"), field("int8")),
+            (field("vector-dim"), Value::Int(3)),
+            (field("size"), Value::Int(10)),
+            (field("max-level"), Value::Int(2)),
+            (field("attributes-count"), Value::Int(4)),
+        ];
+
+        let resp2 = Value::Array(
+            fields
+                .iter()
+                .cloned()
+                .flat_map(|(name, value)| [name, value])
+                .collect(),
+        );
+        for reply in [resp2, Value::Map(fields)] {
+            let info = VectorSetInfo::from_redis_value(reply).unwrap();
+            assert_eq!(info.quantization, Some(VectorQuantization::Q8));
+            assert_eq!(info.dimension, Some(3));
+            assert_eq!(info.size, Some(10));
+            assert_eq!(info.attributes_count, Some(4));
+            assert_eq!(info.other.len(), 1);
+            assert_eq!(info.other["max-level"], Value::Int(2));
+        }
+
+        // Unknown quantizations are kept as raw values
+        let info =
+            VectorSetInfo::from_redis_value(Value::Map(vec![(field("quant-type"), field("f16"))]))
+                .unwrap();
+        assert_eq!(info.quantization, None);
+        assert_eq!(info.other["quant-type"], field("f16"));
+
+        assert!(VectorSetInfo::from_redis_value(Value::Int(1)).is_err());
+    }
+}
@@ -0,0 +1,196 @@
+//! Helpers for inspecting commands, e.g. when logging them.
+use crate::cmd::{Arg, Cmd};
+
+const REDACTED: &[u8] = b"<redacted>";
+
+/// Renders a command both as the arguments sent to the server and as a line that can be pasted into `redis-cli`.
+///
+/// Cursor arguments are rendered with the command's current cursor. In the CLI form, arguments that are empty
+/// or contain whitespace, quotes, backslashes or non-printable bytes are double-quoted and escaped the way
+/// `redis-cli` expects them.
+///
+/// Passwords are replaced with `<redacted>` in both forms, so that the output can be logged safely.
+/// This covers `AUTH`, the `AUTH` option of `HELLO` and the `AUTH`/`AUTH2` options of `MIGRATE`.
+/// Other arguments, including those of commands which may carry secrets such as `CONFIG SET` or
+/// `ACL SETUSER`, are rendered as they are.
+///
+/// ```rust
+/// use redis::debug::render_cmd;
+///
+/// let mut cmd = redis::cmd("SET");
+/// cmd.arg("greeting").arg("hello world");
+/// let (args, cli) = render_cmd(&cmd);
+/// assert_eq!(args, [b"SET".to_vec(), b"greeting".to_vec(), b"hello world".to_vec()]);
+/// assert_eq!(cli, r#"SET greeting "hello world""#);
+///
+/// let (_, cli) = render_cmd(redis::cmd("AUTH").arg("user").arg("secret"));
+/// assert_eq!(cli, "AUTH user <redacted>");
+/// ```
+pub fn render_cmd(cmd: &Cmd) -> (Vec<Vec<u8>>, String) {
+    let mut args: Vec<Vec<u8>> = cmd
+        .args_iter()
+        .map(|arg| match arg {
+            Arg::Simple(bytes) => bytes.to_vec(),
+            Arg::Cursor => cmd.cursor.unwrap_or(0).to_string().into_bytes(),
+        })
+        .collect();
+    redact_passwords(&mut args);
+    let cli = args
+        .iter()
+        .map(|arg| quote_cli_arg(arg))
+        .collect::<Vec<_>>()
+        .join(" ");
+    (args, cli)
+}
+
+fn redact_passwords(args: &mut [Vec<u8>]) {
+    let is = |arg: &[u8], name: &str| arg.eq_ignore_ascii_case(name.as_bytes());
+    let Some(command) = args.first() else {
+        return;
+    };
+
+    let mut passwords = Vec::new();
+    if is(command, "AUTH") {
+        // AUTH [username] password
+        if args.len() > 1 {
+            passwords.push(args.len() - 1);
+        }
+    } else if is(command, "HELLO") {
+        // HELLO [protover [AUTH username password] [SETNAME clientname]]
+        let mut idx = 2;
+        while idx < args.len() {
+            if is(&args[idx], "AUTH") {
+                passwords.push(idx + 2);
+                idx += 3;
+            } else if is(&args[idx], "SETNAME") {
+                idx += 2;
+            } else {
+                idx += 1;
+            }
+        }
+    } else if is(command, "MIGRATE") {
+        // The options precede the KEYS list, whose names may be anything
+        let mut idx = 6;
+        while idx < args.len() && !is(&args[idx], "KEYS") {
+            if is(&args[idx], "AUTH") {
+                passwords.push(idx + 1);
+                idx += 2;
+            } else if is(&args[idx], "AUTH2") {
+                passwords.push(idx + 2);
+                idx += 3;
+            } else {
+                idx += 1;
+            }
+        }
+    }
+
+    for idx in passwords {
+        if let Some(password) = args.get_mut(idx) {
+            *password = REDACTED.to_vec();
+        }
+    }
+}
+
+fn quote_cli_arg(arg: &[u8]) -> String {
+    let needs_quoting = arg.is_empty()
+        || arg
+            .iter()
+            .any(|&b| !b.is_ascii_graphic() || matches!(b, b'"' | b'\'' | b'\\'));
+    if !needs_quoting {
+        return String::from_utf8_lossy(arg).into_owned();
+    }
+
+    let mut quoted = String::with_capacity(arg.len() + 2);
+    quoted.push('"');
+    for &b in arg {
+        match b {
+            b'"' => quoted.push_str("\\\""),
+            b'\\' => quoted.push_str("\\\\"),
+            b'\n' => quoted.push_str("\\n"),
+            b'\r' => quoted.push_str("\\r"),
+            b'\t' => quoted.push_str("\\t"),
+            b' ' => quoted.push(' '),
+            b if b.is_ascii_graphic() => quoted.push(b as char),
+            b => quoted.push_str(&format!("\\x{b:02x}")),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_cmd;
+    use crate::cmd;
+
+    #[test]
+    fn test_render_cmd() {
+        let mut set = cmd("SET");
+        set.arg("key")
+            .arg("hello world")
+            .arg("")
+            .arg(&b"a\"b\\c\n\x00\xff"[..]);
+        let (args, cli) = render_cmd(&set);
+        assert_eq!(
+            args,
+            [
+                b"SET".to_vec(),
+                b"key".to_vec(),
+                b"hello world".to_vec(),
+                b"".to_vec(),
+                b"a\"b\\c\n\x00\xff".to_vec(),
+            ]
+        );
+        assert_eq!(cli, r#"SET key "hello world" "" "a\"b\\c\n\x00\xff""#);
+
+        let mut scan = cmd("SCAN");
+        scan.cursor_arg(0).arg("MATCH").arg("it's");
+        scan.cursor = Some(42);
+        let (args, cli) = render_cmd(&scan);
+        assert_eq!(args[1], b"42");
+        assert_eq!(cli, r#"SCAN 42 MATCH "it's""#);
+    }
+
+    #[test]
+    fn test_render_cmd_redacts_passwords() {
+        let render = |cmd: &crate::Cmd| render_cmd(cmd).1;
+
+        assert_eq!(render(cmd("AUTH").arg("secret")), "AUTH <redacted>");
+        assert_eq!(
+            render(cmd("auth").arg("user").arg("secret")),
+            "auth user <redacted>"
+        );
+        assert_eq!(
+            render(
+                cmd("HELLO")
+                    .arg(3)
+                    .arg("SETNAME")
+                    .arg("AUTH")
+                    .arg("AUTH")
+                    .arg("user")
+                    .arg("secret")
+            ),
+            "HELLO 3 SETNAME AUTH AUTH user <redacted>"
+        );
+        assert_eq!(
+            render(
+                cmd("MIGRATE")
+                    .arg("host")
+                    .arg(6379)
+                    .arg("")
+                    .arg(0)
+                    .arg(5000)
+                    .arg("AUTH2")
+                    .arg("user")
+                    .arg("secret")
+                    .arg("KEYS")
+                    .arg("AUTH")
+                    .arg("key")
+            ),
+            r#"MIGRATE host 6379 "" 0 5000 AUTH2 user <redacted> KEYS AUTH key"#
+        );
+
+        let (args, _) = render_cmd(cmd("AUTH").arg("secret"));
+        assert_eq!(args[1], b"<redacted>");
+    }
+}
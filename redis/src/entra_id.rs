@@ -234,12 +234,12 @@
 //! [`AsyncConnectionConfig`]: crate::AsyncConnectionConfig
 //! [`ConnectionManagerConfig`]: crate::aio::ConnectionManagerConfig
 
-use crate::RetryConfig;
 use crate::auth::BasicAuth;
 use crate::auth::StreamingCredentialsProvider;
 use crate::auth_management::credentials_management_utils;
 use crate::errors::{ErrorKind, RedisError};
 use crate::types::RedisResult;
+use crate::{RefreshEvent, RetryConfig, TokenRefreshConfig};
 use azure_core::credentials::{AccessToken, Secret, TokenCredential};
 use azure_core::time::OffsetDateTime;
 use azure_identity::{
@@ -247,12 +247,14 @@ use azure_identity::{
     ClientSecretCredentialOptions, DeveloperToolsCredential, DeveloperToolsCredentialOptions,
     ManagedIdentityCredential, ManagedIdentityCredentialOptions,
 };
-use backon::{ExponentialBuilder, Retryable};
+use backon::Retryable;
+use futures_util::future::{Either, select};
 use futures_util::{Stream, StreamExt};
 use log::{debug, error, warn};
-use std::pin::Pin;
+use std::pin::{Pin, pin};
 use std::sync::{Arc, Mutex, RwLock};
-use tokio::sync::mpsc::Sender;
+use tokio::sync::Notify;
+use tokio::sync::watch;
 
 /// The default Redis scope for Azure Managed Redis
 pub const REDIS_SCOPE_DEFAULT: &str = "https://redis.azure.com/.default";
@@ -261,11 +263,29 @@ pub const REDIS_SCOPE_DEFAULT: &str = "https://redis.azure.com/.default";
 /// This buffer ensures the token is refreshed before it actually expires.
 const TOKEN_REFRESH_BUFFER_SECS: u64 = 240;
 
+/// Compute how long to wait before refreshing `access_token`, which was received at `received_at`.
+///
+/// The delay is the configured fraction of the token's remaining lifetime, but never less than the minimal interval.
+/// Tokens that have already expired are refreshed after the minimal interval.
+fn refresh_delay(
+    access_token: &AccessToken,
+    received_at: std::time::SystemTime,
+    refresh_config: &TokenRefreshConfig,
+) -> std::time::Duration {
+    credentials_management_utils::calculate_refresh_threshold(
+        received_at,
+        access_token.expires_on.into(),
+        refresh_config.expiration_refresh_ratio,
+    )
+    .unwrap_or_default()
+    .max(refresh_config.min_refresh_interval)
+}
+
 /// A client certificate in PKCS12 (PFX) that can be used for client certificate authentication.
 ///
 /// The certificate data should be base64-encoded PKCS12 content.
 /// If the PKCS12 archive is password-protected, provide the password via `password`.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ClientCertificate {
     /// Base64-encoded PKCS12 certificate data
     pub base64_pkcs12: String,
@@ -273,11 +293,75 @@ pub struct ClientCertificate {
     pub password: Option<String>,
 }
 
-type Subscriptions = Vec<Sender<RedisResult<BasicAuth>>>;
+impl std::fmt::Debug for ClientCertificate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientCertificate")
+            .field("base64_pkcs12", &"<redacted>")
+            .field("password", &self.password.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl ClientCertificate {
+    /// Check that the certificate data is present and is valid base64.
+    ///
+    /// Whitespace (such as the line breaks of a wrapped base64 file) is ignored.
+    /// This does not check the PKCS12 structure itself, which is parsed by `azure_identity`.
+    pub fn validate(&self) -> RedisResult<()> {
+        use base64::{Engine, engine::general_purpose::STANDARD};
+
+        let data: String = self
+            .base64_pkcs12
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect();
+        if data.is_empty() {
+            return Err(RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "Client certificate data cannot be empty",
+            )));
+        }
+        if let Err(err) = STANDARD.decode(&data) {
+            return Err(RedisError::from((
+                ErrorKind::InvalidClientConfig,
+                "Client certificate data is not valid base64",
+                err.to_string(),
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// How strictly the scopes passed to the provider's constructors are validated.
+///
+/// The constructors validate scopes leniently. To apply [`ScopeValidation::Strict`] with any constructor,
+/// call [`EntraIdCredentialsProvider::validate_scopes_with`] on the scopes first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScopeValidation {
+    /// Only reject empty or whitespace-only scopes.
+    /// This allows custom and OIDC scopes such as `openid` or `offline_access`.
+    #[default]
+    Lenient,
+    /// Additionally require every scope to be of the form `https://<resource>/.default`.
+    Strict,
+}
+
+type RefreshObserver = dyn Fn(&RefreshEvent) + Send + Sync;
+type Subscriptions = Vec<watch::Sender<Option<RedisResult<BasicAuth>>>>;
 type SharedSubscriptions = Arc<Mutex<Subscriptions>>;
 
 struct TaskAborter {
     handle: tokio::task::JoinHandle<()>,
+    shutdown: Arc<Notify>,
+}
+
+/// Run `future` to completion unless a shutdown is requested first, in which case `None` is returned.
+async fn unless_shutdown<T>(shutdown: &Notify, future: impl Future<Output = T>) -> Option<T> {
+    match select(pin!(future), pin!(shutdown.notified())).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
 }
 
 impl Drop for TaskAborter {
@@ -293,11 +377,32 @@ pub struct EntraIdCredentialsProvider {
     background_handle: Arc<Mutex<Option<TaskAborter>>>,
     subscribers: SharedSubscriptions,
     current_credentials: Arc<RwLock<Option<BasicAuth>>>,
+    refresh_observer: Arc<RwLock<Option<Arc<RefreshObserver>>>>,
+    refresh_requested: Arc<Notify>,
 }
 
 impl EntraIdCredentialsProvider {
     /// Validate that scopes are not empty
     fn validate_scopes(scopes: &[String]) -> RedisResult<()> {
+        Self::validate_scopes_with(scopes, ScopeValidation::Lenient)
+    }
+
+    /// Validate `scopes` according to `validation`
+    ///
+    /// Returns an [`ErrorKind::InvalidClientConfig`] error naming the first invalid scope.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use redis::{EntraIdCredentialsProvider, ScopeValidation};
+    ///
+    /// # fn example() -> redis::RedisResult<()> {
+    /// let scopes = vec!["https://redis.azure.com/.default".to_string()];
+    /// EntraIdCredentialsProvider::validate_scopes_with(&scopes, ScopeValidation::Strict)?;
+    /// let provider = EntraIdCredentialsProvider::new_system_assigned_managed_identity_with_scopes(scopes, None)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_scopes_with(scopes: &[String], validation: ScopeValidation) -> RedisResult<()> {
         if scopes.is_empty() {
             return Err(RedisError::from((
                 ErrorKind::InvalidClientConfig,
@@ -312,6 +417,19 @@ impl EntraIdCredentialsProvider {
                     "Scope cannot be empty or whitespace-only",
                 )));
             }
+
+            if validation == ScopeValidation::Strict {
+                let resource = scope
+                    .strip_prefix("https://")
+                    .and_then(|rest| rest.strip_suffix("/.default"));
+                if resource.is_none_or(|resource| resource.is_empty()) {
+                    return Err(RedisError::from((
+                        ErrorKind::InvalidClientConfig,
+                        "Scope must be of the form `https://<resource>/.default`",
+                        scope.clone(),
+                    )));
+                }
+            }
         }
 
         Ok(())
@@ -319,10 +437,7 @@ impl EntraIdCredentialsProvider {
 
     /// Convert Azure AccessToken to Redis BasicAuth
     fn convert_credentials(username: String, access_token: &AccessToken) -> BasicAuth {
-        BasicAuth {
-            username,
-            password: access_token.token.secret().to_string(),
-        }
+        BasicAuth::new(username, access_token.token.secret().to_string())
     }
 
     /// Convert Azure Core error to Redis error
@@ -334,15 +449,6 @@ impl EntraIdCredentialsProvider {
         ))
     }
 
-    /// Convert Azure Core error to Redis error
-    fn convert_error_ref(err: &azure_core::Error) -> RedisError {
-        RedisError::from((
-            ErrorKind::AuthenticationFailed,
-            "Entra ID authentication failed",
-            format!("{err}"),
-        ))
-    }
-
     /// Unwrap a credentials provider from its `Arc` wrapper.
     ///
     /// The azure_identity crate returns credentials wrapped in an `Arc`, while sole ownership is expected at construction time.
@@ -356,31 +462,19 @@ impl EntraIdCredentialsProvider {
         })
     }
 
-    async fn notify_subscribers(
+    /// Deliver `credentials` to every subscriber without waiting on any of them.
+    ///
+    /// Each subscriber only holds the latest update, so one that has not consumed the previous update
+    /// gets `credentials` in its place and cannot stall the refresh service. Subscribers whose streams
+    /// were dropped are removed.
+    fn notify_subscribers(
         subscribers_arc: &SharedSubscriptions,
-        username: &str,
-        token_response: Result<AccessToken, azure_core::Error>,
+        credentials: RedisResult<BasicAuth>,
     ) {
-        let subscribers = {
-            let mut guard = subscribers_arc
-                .lock()
-                .expect("could not acquire lock for subscribers");
-            guard.retain(|sender| !sender.is_closed());
-            guard.clone()
-        };
-
-        futures_util::future::join_all(subscribers.iter().map(|sender| {
-            let token_response = token_response.as_ref();
-            let response = match token_response {
-                Ok(access_token) => {
-                    Ok(Self::convert_credentials(username.to_owned(), access_token))
-                }
-                Err(error) => Err(Self::convert_error_ref(error)),
-            };
-
-            sender.send(response)
-        }))
-        .await;
+        subscribers_arc
+            .lock()
+            .expect("could not acquire lock for subscribers")
+            .retain(|sender| sender.send(Some(credentials.clone())).is_ok());
     }
 
     /// Start the background refresh service
@@ -398,71 +492,164 @@ impl EntraIdCredentialsProvider {
 
         let subscribers_arc = Arc::clone(&self.subscribers);
         let current_credentials_arc = Arc::clone(&self.current_credentials);
+        let refresh_observer_arc = Arc::clone(&self.refresh_observer);
+        let refresh_requested = Arc::clone(&self.refresh_requested);
 
         let credential_provider_arc = Arc::clone(&self.credential_provider);
         let scopes = self.scopes.clone();
+        let shutdown = Arc::new(Notify::new());
+        let shutdown_signal = Arc::clone(&shutdown);
 
         let handle = tokio::spawn(async move {
             let scopes: Vec<&str> = scopes.iter().map(|s| s.as_str()).collect();
-            let mut next_sleep_duration;
-            let mut username = "default".to_string();
-            let RetryConfig {
-                exponent_base,
-                min_delay,
-                max_delay,
-                number_of_retries,
-            } = retry_config;
-            let mut strategy = ExponentialBuilder::default()
-                .with_factor(exponent_base)
-                .with_min_delay(min_delay)
-                .with_max_times(number_of_retries)
-                .with_jitter();
-
-            if let Some(mex_delay) = max_delay {
-                strategy = strategy.with_max_delay(mex_delay);
-            }
+            let strategy = retry_config.backoff_builder();
 
             loop {
                 debug!("Refreshing token.");
-                let get_auth = || async { credential_provider_arc.get_token(&scopes, None).await };
+                let mut attempts = 0;
+                let get_auth = || {
+                    attempts += 1;
+                    async { credential_provider_arc.get_token(&scopes, None).await }
+                };
 
+                let started_at = tokio::time::Instant::now();
                 let token_response = get_auth
                     .retry(strategy)
                     .sleep(|duration| async move { tokio::time::sleep(duration).await })
-                    .notify(|err, duration| warn!("An error `{err}` occurred while refreshing the token. Sleeping for {duration:?}"))
-                    .await;
-
-                if let Ok(ref access_token) = token_response {
-                    username = match credentials_management_utils::extract_oid_from_jwt(
-                        access_token.token.secret(),
-                    ) {
-                        Ok(object_id) => object_id,
-                        Err(error) => {
-                            warn!("Failed to extract OID: {error}");
-                            "default".to_string()
-                        }
-                    };
-
-                    *current_credentials_arc.write().unwrap() =
-                        Some(Self::convert_credentials(username.clone(), access_token));
-
-                    next_sleep_duration = compute_sleep_duration_on_success(access_token);
-                } else {
-                    error!("Maximum token refresh attempts reached. Stopping token refresh.");
-                    Self::notify_subscribers(&subscribers_arc, &username, token_response).await;
+                    .adjust(|_, delay| retry_config.delay_within_budget(started_at.elapsed(), delay))
+                    .notify(|err, duration| warn!("An error `{err}` occurred while refreshing the token. Sleeping for {duration:?}"));
+                let Some(token_response) = unless_shutdown(&shutdown_signal, token_response).await
+                else {
+                    debug!("Token refresh service stopped.");
                     break;
+                };
+
+                let refresh_observer = refresh_observer_arc.read().unwrap().clone();
+                if let Some(refresh_observer) = refresh_observer {
+                    refresh_observer(&RefreshEvent {
+                        succeeded: token_response.is_ok(),
+                        duration: started_at.elapsed(),
+                        attempts,
+                    });
                 }
 
-                Self::notify_subscribers(&subscribers_arc, &username, token_response).await;
-
-                tokio::time::sleep(std::time::Duration::from_millis(
+                let access_token = match token_response {
+                    Ok(access_token) => access_token,
+                    Err(err) => {
+                        error!("Maximum token refresh attempts reached. Stopping token refresh.");
+                        let err = credentials_management_utils::refresh_attempts_exhausted_error(
+                            "Entra ID authentication failed",
+                            attempts,
+                            err,
+                        );
+                        Self::notify_subscribers(&subscribers_arc, Err(err));
+                        break;
+                    }
+                };
+
+                let username = match credentials_management_utils::extract_oid_from_jwt(
+                    access_token.token.secret(),
+                ) {
+                    Ok(object_id) => object_id,
+                    Err(error) => {
+                        warn!("Failed to extract OID: {error}");
+                        "default".to_string()
+                    }
+                };
+                let credentials = Self::convert_credentials(username, &access_token);
+                *current_credentials_arc.write().unwrap() = Some(credentials.clone());
+
+                Self::notify_subscribers(&subscribers_arc, Ok(credentials));
+
+                let next_sleep_duration = compute_sleep_duration_on_success(&access_token);
+                let sleep = tokio::time::sleep(std::time::Duration::from_millis(
                     next_sleep_duration.as_millis() as u64,
-                ))
-                .await;
+                ));
+                // An invalidation cuts the sleep short
+                let sleep = async {
+                    let refresh_requested = pin!(refresh_requested.notified());
+                    select(pin!(sleep), refresh_requested).await;
+                };
+                if unless_shutdown(&shutdown_signal, sleep).await.is_none() {
+                    debug!("Token refresh service stopped.");
+                    break;
+                }
             }
         });
 
-        *self.background_handle.lock().unwrap() = Some(TaskAborter { handle });
+        *self.background_handle.lock().unwrap() = Some(TaskAborter { handle, shutdown });
+    }
+
+    /// Set a callback that is invoked after every token refresh, successful or not.
+    ///
+    /// This can be used to export metrics about the refreshes. The callback is shared by all clones
+    /// of this provider and replaces any previously set callback. It is called from the background
+    /// refresh task, so it should not block.
+    pub fn set_refresh_observer<F>(&self, observer: F)
+    where
+        F: Fn(&RefreshEvent) + Send + Sync + 'static,
+    {
+        *self.refresh_observer.write().unwrap() = Some(Arc::new(observer));
+    }
+
+    /// Discard the cached credentials and make the background refresh service fetch a new token right away.
+    ///
+    /// This is useful when the server rejects the current credentials, e.g. with an authentication error,
+    /// before the token's scheduled refresh. Until the new token arrives, new subscribers are not
+    /// replayed any credentials. If the service is not running, e.g. because it was never started or
+    /// it gave up after exhausting its retries, nothing happens, since no refresh would replace the cached credentials.
+    pub fn invalidate(&self) {
+        let background_handle = self.background_handle.lock().unwrap();
+        if background_handle
+            .as_ref()
+            .is_some_and(|task| !task.handle.is_finished())
+        {
+            *self.current_credentials.write().unwrap() = None;
+            self.refresh_requested.notify_one();
+        }
+    }
+
+    /// Get a stream of the credentials produced by future refreshes.
+    ///
+    /// Unlike [`subscribe`](StreamingCredentialsProvider::subscribe), the cached credentials are not
+    /// replayed first. This suits consumers that already hold the current credentials.
+    ///
+    /// A stream that is not polled between refreshes skips the intermediate updates and yields
+    /// only the latest one, which may be the error that stopped the refresh service.
+    pub fn subscribe_updates_only(
+        &self,
+    ) -> Pin<Box<dyn Stream<Item = RedisResult<BasicAuth>> + Send + 'static>> {
+        let (tx, rx) = watch::channel(None);
+
+        self.subscribers
+            .lock()
+            .expect("could not acquire lock for subscribers")
+            .push(tx);
+
+        futures_util::stream::unfold(rx, |mut rx| async move {
+            loop {
+                rx.changed().await.ok()?;
+                let item = rx.borrow_and_update().clone();
+                if let Some(item) = item {
+                    return Some((item, rx));
+                }
+            }
+        })
+        .boxed()
+    }
+
+    /// Stop the background refresh service and wait for it to finish.
+    ///
+    /// The service is only interrupted while it is waiting for a token or for the next refresh,
+    /// so the cached credentials and the subscribers are never left with a partial update.
+    /// The provider can be started again afterwards. Dropping the last clone of a provider
+    /// also stops the service, but without waiting for it.
+    pub async fn stop(&self) {
+        let Some(mut task) = self.background_handle.lock().unwrap().take() else {
+            return;
+        };
+        task.shutdown.notify_one();
+        let _ = (&mut task.handle).await;
     }
 
     /// Create a new provider using the DeveloperToolsCredential
@@ -485,6 +672,8 @@ impl EntraIdCredentialsProvider {
             background_handle: Default::default(),
             subscribers: Default::default(),
             current_credentials: Default::default(),
+            refresh_observer: Default::default(),
+            refresh_requested: Default::default(),
         })
     }
 
@@ -521,6 +710,8 @@ impl EntraIdCredentialsProvider {
             background_handle: Default::default(),
             subscribers: Default::default(),
             current_credentials: Default::default(),
+            refresh_observer: Default::default(),
+            refresh_requested: Default::default(),
         })
     }
 
@@ -548,6 +739,7 @@ impl EntraIdCredentialsProvider {
         mut options: Option<ClientCertificateCredentialOptions>,
     ) -> RedisResult<Self> {
         Self::validate_scopes(&scopes)?;
+        client_certificate.validate()?;
         if let Some(password) = client_certificate.password {
             if let Some(ref mut opts) = options {
                 opts.password = Some(Secret::new(password));
@@ -571,6 +763,8 @@ impl EntraIdCredentialsProvider {
             background_handle: Default::default(),
             subscribers: Default::default(),
             current_credentials: Default::default(),
+            refresh_observer: Default::default(),
+            refresh_requested: Default::default(),
         })
     }
 
@@ -596,6 +790,8 @@ impl EntraIdCredentialsProvider {
             background_handle: Default::default(),
             subscribers: Default::default(),
             current_credentials: Default::default(),
+            refresh_observer: Default::default(),
+            refresh_requested: Default::default(),
         })
     }
 
@@ -621,6 +817,8 @@ impl EntraIdCredentialsProvider {
             background_handle: Default::default(),
             subscribers: Default::default(),
             current_credentials: Default::default(),
+            refresh_observer: Default::default(),
+            refresh_requested: Default::default(),
         })
     }
 
@@ -636,6 +834,8 @@ impl EntraIdCredentialsProvider {
             background_handle: Default::default(),
             subscribers: Default::default(),
             current_credentials: Default::default(),
+            refresh_observer: Default::default(),
+            refresh_requested: Default::default(),
         })
     }
 
@@ -655,20 +855,23 @@ impl EntraIdCredentialsProvider {
                 })
         });
     }
+
+    /// Start the background token refresh service, scheduling refreshes according to `refresh_config`.
+    ///
+    /// Unlike [`start`](Self::start), which refreshes tokens shortly before they expire, this refreshes a token
+    /// once `expiration_refresh_ratio` of its lifetime has elapsed, waiting at least `min_refresh_interval`
+    /// between refreshes. Failed refresh attempts are retried according to `refresh_config.retry_config`.
+    pub fn start_with_refresh_config(&mut self, refresh_config: TokenRefreshConfig) {
+        let retry_config = refresh_config.retry_config.clone();
+        self.start_refresh_service(retry_config, move |access_token| {
+            refresh_delay(access_token, std::time::SystemTime::now(), &refresh_config)
+        });
+    }
 }
 
 impl StreamingCredentialsProvider for EntraIdCredentialsProvider {
     fn subscribe(&self) -> Pin<Box<dyn Stream<Item = RedisResult<BasicAuth>> + Send + 'static>> {
-        let (tx, rx) = tokio::sync::mpsc::channel::<RedisResult<BasicAuth>>(1);
-
-        self.subscribers
-            .lock()
-            .expect("could not acquire lock for subscribers")
-            .push(tx);
-
-        let stream = futures_util::stream::unfold(rx, |mut rx| async move {
-            rx.recv().await.map(|item| (item, rx))
-        });
+        let stream = self.subscribe_updates_only();
 
         if let Some(creds) = self.current_credentials.read().unwrap().clone() {
             futures_util::stream::once(async move { Ok(creds) })
@@ -678,6 +881,10 @@ impl StreamingCredentialsProvider for EntraIdCredentialsProvider {
             stream.boxed()
         }
     }
+
+    fn invalidate(&self) {
+        EntraIdCredentialsProvider::invalidate(self);
+    }
 }
 
 impl std::fmt::Debug for EntraIdCredentialsProvider {
@@ -748,6 +955,145 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_client_certificate_validation() {
+        let certificate = |data: &str| ClientCertificate {
+            base64_pkcs12: data.to_string(),
+            password: None,
+        };
+
+        assert!(
+            certificate("MIIKcQIBAzCCCjcGCSqGSIb3DQEHAaCCCigEggokMIIKIDCCBNc=")
+                .validate()
+                .is_ok()
+        );
+        // Wrapped base64 files are accepted
+        assert!(
+            certificate("MIIKcQIBAzCCCjcGCSqG\nSIb3DQEHAaCCCigEggok\nMIIKIDCCBNc=\n")
+                .validate()
+                .is_ok()
+        );
+
+        let err = certificate("").validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidClientConfig);
+        assert!(err.to_string().contains("cannot be empty"));
+
+        let err = certificate(" \n ").validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidClientConfig);
+        assert!(err.to_string().contains("cannot be empty"));
+
+        let err = certificate("-----BEGIN CERTIFICATE-----")
+            .validate()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidClientConfig);
+        assert!(err.to_string().contains("not valid base64"));
+    }
+
+    #[test]
+    fn test_client_certificate_debug_redacts_secrets() {
+        let certificate = ClientCertificate {
+            base64_pkcs12: "MIIKcQIBAzCCCjcGCSqG".to_string(),
+            password: Some("hunter2".to_string()),
+        };
+        let debug = format!("{certificate:?}");
+        assert!(!debug.contains("MIIKcQIBAzCCCjcGCSqG"));
+        assert!(!debug.contains("hunter2"));
+        assert!(debug.contains("<redacted>"));
+    }
+
+    #[test]
+    fn test_client_certificate_provider_rejects_invalid_certificate() {
+        let result = EntraIdCredentialsProvider::new_client_certificate(
+            "tenant".to_string(),
+            "client".to_string(),
+            ClientCertificate {
+                base64_pkcs12: "not base64!".to_string(),
+                password: None,
+            },
+        );
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidClientConfig);
+    }
+
+    #[test]
+    fn test_strict_scope_validation() {
+        let validate = |scope: &str, validation| {
+            EntraIdCredentialsProvider::validate_scopes_with(&[scope.to_string()], validation)
+        };
+
+        for scope in [REDIS_SCOPE_DEFAULT, "https://custom.scope/.default"] {
+            assert!(validate(scope, ScopeValidation::Strict).is_ok());
+        }
+
+        for scope in [
+            "openid",
+            "http://redis.azure.com/.default",
+            "https://redis.azure.com",
+            "https://redis.azure.com/user.read",
+            "https:///.default",
+        ] {
+            // Custom scopes are still accepted by default
+            assert!(validate(scope, ScopeValidation::Lenient).is_ok());
+
+            let err = validate(scope, ScopeValidation::Strict).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidClientConfig);
+            assert!(err.to_string().contains(scope));
+        }
+
+        // Both modes reject empty scopes
+        for validation in [ScopeValidation::Lenient, ScopeValidation::Strict] {
+            assert!(validate(" ", validation).is_err());
+        }
+
+        // Every scope is checked
+        let scopes = vec![
+            REDIS_SCOPE_DEFAULT.to_string(),
+            "https://redis.azure.com".to_string(),
+        ];
+        assert!(
+            EntraIdCredentialsProvider::validate_scopes_with(&scopes, ScopeValidation::Lenient)
+                .is_ok()
+        );
+        let err =
+            EntraIdCredentialsProvider::validate_scopes_with(&scopes, ScopeValidation::Strict)
+                .unwrap_err();
+        assert!(err.to_string().contains("https://redis.azure.com"));
+    }
+
+    #[test]
+    fn test_refresh_delay() {
+        let received_at = std::time::SystemTime::now();
+        let token_expiring_in = |lifetime| AccessToken {
+            token: Secret::new("token"),
+            expires_on: OffsetDateTime::from(received_at) + lifetime,
+        };
+        let access_token = token_expiring_in(azure_core::time::Duration::hours(1));
+
+        let refresh_config = TokenRefreshConfig::default();
+        assert_eq!(
+            refresh_delay(&access_token, received_at, &refresh_config),
+            std::time::Duration::from_secs(2880)
+        );
+
+        let refresh_config = refresh_config.set_expiration_refresh_ratio(0.5);
+        assert_eq!(
+            refresh_delay(&access_token, received_at, &refresh_config),
+            std::time::Duration::from_secs(1800)
+        );
+
+        // Tokens that are about to expire, or have already expired, are not refreshed more often than the floor
+        let refresh_config =
+            refresh_config.set_min_refresh_interval(std::time::Duration::from_secs(10));
+        for lifetime in [
+            azure_core::time::Duration::seconds(4),
+            azure_core::time::Duration::seconds(-4),
+        ] {
+            assert_eq!(
+                refresh_delay(&token_expiring_in(lifetime), received_at, &refresh_config),
+                std::time::Duration::from_secs(10)
+            );
+        }
+    }
+
     #[test]
     fn test_custom_scopes() {
         let custom_scopes = vec!["https://custom.scope/.default".to_string()];
@@ -762,8 +1108,11 @@ mod tests {
 
 #[cfg(all(feature = "entra-id", test))]
 mod entra_id_mock_tests {
+    use super::TOKEN_REFRESH_BUFFER_SECS;
+    use crate::errors::ErrorKind;
     use crate::{
         EntraIdCredentialsProvider, REDIS_SCOPE_DEFAULT, RetryConfig, StreamingCredentialsProvider,
+        TokenRefreshConfig,
     };
     use azure_core::Error as AzureError;
     use azure_core::credentials::{AccessToken, Secret, TokenCredential};
@@ -858,21 +1207,22 @@ mod entra_id_mock_tests {
             }
         }
 
-        /// Create a mock that simulates multiple token refreshes
+        /// Create a mock that simulates multiple token refreshes, about a second apart when started with `start`
         fn multiple_tokens() -> Self {
-            let time_now = OffsetDateTime::now_utc();
+            let refreshed_at =
+                OffsetDateTime::now_utc() + Duration::seconds(TOKEN_REFRESH_BUFFER_SECS as i64);
             let tokens = vec![
                 Ok(AccessToken {
                     token: Secret::new(MOCKED_TOKEN_1.as_str()),
-                    expires_on: time_now + Duration::seconds(1),
+                    expires_on: refreshed_at + Duration::seconds(1),
                 }),
                 Ok(AccessToken {
                     token: Secret::new(MOCKED_TOKEN_2.as_str()),
-                    expires_on: time_now + Duration::seconds(2),
+                    expires_on: refreshed_at + Duration::seconds(2),
                 }),
                 Ok(AccessToken {
                     token: Secret::new(MOCKED_TOKEN_3.as_str()),
-                    expires_on: time_now + Duration::seconds(3),
+                    expires_on: refreshed_at + Duration::seconds(3),
                 }),
             ];
 
@@ -952,9 +1302,9 @@ mod entra_id_mock_tests {
         let mut stream = provider.subscribe();
         let credentials = stream.next().await.unwrap().unwrap();
 
-        assert!(!credentials.username.is_empty());
+        assert!(credentials.username.is_some());
         assert!(!credentials.password.is_empty());
-        assert_eq!(credentials.username, OID_CLAIM_VALUE);
+        assert_eq!(credentials.username(), OID_CLAIM_VALUE);
         assert_eq!(credentials.password, MOCKED_TOKEN.as_str());
     }
 
@@ -990,6 +1340,292 @@ mod entra_id_mock_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_mock_authentication_failure_reports_attempts() {
+        init_logger();
+        let mock_credential = MockTokenCredential::failure();
+        let call_count_ref = mock_credential.call_count.clone();
+
+        let mut provider = create_mock_entra_id_credentials_provider(
+            mock_credential,
+            vec![REDIS_SCOPE_DEFAULT.to_string()],
+        );
+        provider.start(
+            RetryConfig::default()
+                .set_number_of_retries(2)
+                .set_min_delay(std::time::Duration::from_millis(10))
+                .set_max_delay(std::time::Duration::from_millis(100)),
+        );
+
+        let mut stream = provider.subscribe();
+        let error = stream.next().await.unwrap().unwrap_err();
+        assert_eq!(call_count_ref.load(Ordering::SeqCst), 3);
+        assert_eq!(error.kind(), crate::ErrorKind::AuthenticationFailed);
+        assert!(error.to_string().contains("after 3 attempt(s)"));
+    }
+
+    #[tokio::test]
+    async fn test_mock_refresh_observer() {
+        init_logger();
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let record_events = |provider: &EntraIdCredentialsProvider| {
+            let events = events.clone();
+            provider.set_refresh_observer(move |event| events.lock().unwrap().push(event.clone()));
+        };
+
+        let mut provider = create_mock_entra_id_credentials_provider(
+            MockTokenCredential::success(),
+            vec![REDIS_SCOPE_DEFAULT.to_string()],
+        );
+        record_events(&provider);
+        provider.start(RetryConfig::default());
+        provider.subscribe().next().await.unwrap().unwrap();
+        provider.stop().await;
+        {
+            let events = events.lock().unwrap();
+            assert_eq!(events.len(), 1);
+            assert!(events[0].succeeded);
+            assert_eq!(events[0].attempts, 1);
+        }
+
+        events.lock().unwrap().clear();
+        let mut provider = create_mock_entra_id_credentials_provider(
+            MockTokenCredential::failure(),
+            vec![REDIS_SCOPE_DEFAULT.to_string()],
+        );
+        record_events(&provider);
+        provider.start(
+            RetryConfig::default()
+                .set_number_of_retries(2)
+                .set_min_delay(std::time::Duration::from_millis(10))
+                .set_max_delay(std::time::Duration::from_millis(100)),
+        );
+        provider.subscribe().next().await.unwrap().unwrap_err();
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(!events[0].succeeded);
+        assert_eq!(events[0].attempts, 3);
+        assert!(events[0].duration >= std::time::Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn test_mock_invalidate_forces_refresh() {
+        init_logger();
+        let mock_credential = MockTokenCredential::success();
+        let call_count_ref = mock_credential.call_count.clone();
+        let mut provider = create_mock_entra_id_credentials_provider(
+            mock_credential,
+            vec![REDIS_SCOPE_DEFAULT.to_string()],
+        );
+        provider.start(RetryConfig::default());
+
+        let mut stream = provider.subscribe();
+        stream.next().await.unwrap().unwrap();
+        assert_eq!(call_count_ref.load(Ordering::SeqCst), 1);
+
+        // The token is valid for an hour, so only the invalidation triggers another refresh
+        provider.invalidate();
+        assert!(provider.current_credentials.read().unwrap().is_none());
+        let credentials = stream.next().await.unwrap().unwrap();
+        assert_eq!(credentials.password, MOCKED_TOKEN.as_str());
+        assert_eq!(call_count_ref.load(Ordering::SeqCst), 2);
+        assert!(provider.current_credentials.read().unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_mock_subscribe_updates_only_skips_cached_credentials() {
+        init_logger();
+        let mut provider = create_mock_entra_id_credentials_provider(
+            MockTokenCredential::success(),
+            vec![REDIS_SCOPE_DEFAULT.to_string()],
+        );
+        provider.start(RetryConfig::default());
+        provider.subscribe().next().await.unwrap().unwrap();
+
+        // `subscribe` replays the cached credentials, `subscribe_updates_only` waits for a refresh
+        let mut replaying_stream = provider.subscribe();
+        let mut updates_stream = provider.subscribe_updates_only();
+        let credentials = replaying_stream.next().await.unwrap().unwrap();
+        assert_eq!(credentials.password, MOCKED_TOKEN.as_str());
+        let no_update =
+            tokio::time::timeout(std::time::Duration::from_millis(100), updates_stream.next())
+                .await;
+        assert!(no_update.is_err());
+
+        provider.invalidate();
+        let credentials = updates_stream.next().await.unwrap().unwrap();
+        assert_eq!(credentials.password, MOCKED_TOKEN.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_mock_refresh_config_respects_min_refresh_interval() {
+        init_logger();
+        const MIN_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
+        // An expired token has no refresh threshold, so only the minimal interval delays the next refresh
+        let expired_token = AccessToken {
+            token: Secret::new(MOCKED_TOKEN.as_str()),
+            expires_on: OffsetDateTime::now_utc() - Duration::minutes(1),
+        };
+        let call_count_ref = Arc::new(AtomicUsize::new(0));
+        let mock_credential = MockTokenCredential {
+            call_count: call_count_ref.clone(),
+            responses: Arc::new(Mutex::new(VecDeque::from(vec![Ok(expired_token)]))),
+        };
+        let mut provider = create_mock_entra_id_credentials_provider(
+            mock_credential,
+            vec![REDIS_SCOPE_DEFAULT.to_string()],
+        );
+        provider.start_with_refresh_config(
+            TokenRefreshConfig::default().set_min_refresh_interval(MIN_REFRESH_INTERVAL),
+        );
+
+        let mut stream = provider.subscribe();
+        stream.next().await.unwrap().unwrap();
+        let first_refresh_at = tokio::time::Instant::now();
+        stream.next().await.unwrap().unwrap();
+        // Allow for the time between the first refresh and its delivery
+        assert!(
+            first_refresh_at.elapsed()
+                >= MIN_REFRESH_INTERVAL - std::time::Duration::from_millis(50)
+        );
+        assert_eq!(call_count_ref.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_mock_stuck_subscriber_does_not_block_refresh() {
+        init_logger();
+        let mock_credential = MockTokenCredential::success();
+        let call_count_ref = mock_credential.call_count.clone();
+        let mut provider = create_mock_entra_id_credentials_provider(
+            mock_credential,
+            vec![REDIS_SCOPE_DEFAULT.to_string()],
+        );
+
+        // Never polled while the refreshes happen
+        let stuck_stream = provider.subscribe_updates_only();
+        let mut healthy_stream = provider.subscribe_updates_only();
+        provider.start(RetryConfig::default());
+        healthy_stream.next().await.unwrap().unwrap();
+
+        for expected_calls in [2, 3] {
+            provider.invalidate();
+            let credentials = healthy_stream.next().await.unwrap().unwrap();
+            assert_eq!(credentials.password, MOCKED_TOKEN.as_str());
+            assert_eq!(call_count_ref.load(Ordering::SeqCst), expected_calls);
+        }
+        assert_eq!(provider.subscribers.lock().unwrap().len(), 2);
+
+        // Dropped subscribers are still pruned on the next refresh
+        drop(stuck_stream);
+        provider.invalidate();
+        healthy_stream.next().await.unwrap().unwrap();
+        assert_eq!(provider.subscribers.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mock_lagging_subscriber_gets_latest_update() {
+        init_logger();
+        let token = |secret: &str| {
+            Ok(AccessToken {
+                token: Secret::new(secret.to_string()),
+                expires_on: OffsetDateTime::now_utc() + Duration::hours(1),
+            })
+        };
+        let mock_credential = MockTokenCredential {
+            call_count: Arc::new(AtomicUsize::new(0)),
+            responses: Arc::new(Mutex::new(VecDeque::from(vec![
+                token(MOCKED_TOKEN_1.as_str()),
+                token(MOCKED_TOKEN_2.as_str()),
+                token(MOCKED_TOKEN_3.as_str()),
+                token(MOCKED_TOKEN_1.as_str()),
+                Err(AzureError::new(
+                    azure_core::error::ErrorKind::Credential,
+                    "Permanent failure",
+                )),
+            ]))),
+        };
+        let mut provider = create_mock_entra_id_credentials_provider(
+            mock_credential,
+            vec![REDIS_SCOPE_DEFAULT.to_string()],
+        );
+
+        let mut lagging_stream = provider.subscribe_updates_only();
+        let mut healthy_stream = provider.subscribe_updates_only();
+        provider.start(RetryConfig::default().set_number_of_retries(0));
+        healthy_stream.next().await.unwrap().unwrap();
+        for _ in 0..2 {
+            provider.invalidate();
+            healthy_stream.next().await.unwrap().unwrap();
+        }
+
+        // The intermediate tokens were replaced by the newest one
+        let credentials = lagging_stream.next().await.unwrap().unwrap();
+        assert_eq!(credentials.password, MOCKED_TOKEN_3.as_str());
+
+        // The error that stops the service replaces the token the subscriber has not read yet
+        provider.invalidate();
+        healthy_stream.next().await.unwrap().unwrap();
+        provider.invalidate();
+        healthy_stream.next().await.unwrap().unwrap_err();
+        let err = lagging_stream.next().await.unwrap().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AuthenticationFailed);
+    }
+
+    #[tokio::test]
+    async fn test_mock_invalidate_after_refresh_service_stopped() {
+        init_logger();
+        // The token is refreshed right away, and that refresh fails, which stops the service
+        let mock_credential = MockTokenCredential {
+            call_count: Arc::new(AtomicUsize::new(0)),
+            responses: Arc::new(Mutex::new(VecDeque::from(vec![
+                Ok(AccessToken {
+                    token: Secret::new(MOCKED_TOKEN.as_str()),
+                    expires_on: OffsetDateTime::now_utc() + Duration::seconds(1),
+                }),
+                Err(AzureError::new(
+                    azure_core::error::ErrorKind::Credential,
+                    "Permanent failure",
+                )),
+            ]))),
+        };
+        let mut provider = create_mock_entra_id_credentials_provider(
+            mock_credential,
+            vec![REDIS_SCOPE_DEFAULT.to_string()],
+        );
+        provider.start(RetryConfig::default().set_number_of_retries(0));
+
+        let mut stream = provider.subscribe();
+        stream.next().await.unwrap().unwrap();
+        stream.next().await.unwrap().unwrap_err();
+        tokio::time::timeout(std::time::Duration::from_secs(1), async {
+            while !provider
+                .background_handle
+                .lock()
+                .unwrap()
+                .as_ref()
+                .unwrap()
+                .handle
+                .is_finished()
+            {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        // No refresh would follow, so the cached credentials are kept and still replayed
+        provider.invalidate();
+        let credentials = tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            provider.subscribe().next(),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+        assert_eq!(credentials.password, MOCKED_TOKEN.as_str());
+    }
+
     #[tokio::test]
     async fn test_mock_retry_mechanism() {
         init_logger();
@@ -1011,7 +1647,7 @@ mod entra_id_mock_tests {
         // Eventually should get successful credentials
         let mut stream = provider.subscribe();
         let credentials = stream.next().await.unwrap().unwrap();
-        assert_eq!(credentials.username, OID_CLAIM_VALUE);
+        assert_eq!(credentials.username(), OID_CLAIM_VALUE);
         assert_eq!(credentials.password, MOCKED_TOKEN.as_str());
     }
 
@@ -1061,24 +1697,60 @@ mod entra_id_mock_tests {
         // Wait for the first token to be received
         let credentials = stream.next().await.unwrap().unwrap();
         assert!(call_count_ref.load(Ordering::SeqCst) >= 1);
-        assert_eq!(credentials.username, OID_CLAIM_VALUE);
+        assert_eq!(credentials.username(), OID_CLAIM_VALUE);
         assert_eq!(credentials.password, MOCKED_TOKEN_1.as_str());
 
         // Wait for the next token to be received
         tokio::time::sleep(std::time::Duration::from_secs(1)).await;
         let credentials = stream.next().await.unwrap().unwrap();
         assert!(call_count_ref.load(Ordering::SeqCst) >= 2);
-        assert_eq!(credentials.username, OID_CLAIM_VALUE);
+        assert_eq!(credentials.username(), OID_CLAIM_VALUE);
         assert_eq!(credentials.password, MOCKED_TOKEN_2.as_str());
 
         // Wait for the next token to be received
         tokio::time::sleep(std::time::Duration::from_secs(1)).await;
         let credentials = stream.next().await.unwrap().unwrap();
         assert!(call_count_ref.load(Ordering::SeqCst) >= 3);
-        assert_eq!(credentials.username, OID_CLAIM_VALUE);
+        assert_eq!(credentials.username(), OID_CLAIM_VALUE);
         assert_eq!(credentials.password, MOCKED_TOKEN_3.as_str());
     }
 
+    #[tokio::test]
+    async fn test_mock_cloned_providers_share_refreshed_credentials() {
+        init_logger();
+        let mock_credential = MockTokenCredential::multiple_tokens();
+        let call_count_ref = mock_credential.call_count.clone();
+        let mut provider = create_mock_entra_id_credentials_provider(
+            mock_credential,
+            vec![REDIS_SCOPE_DEFAULT.to_string()],
+        );
+        let cloned_provider = provider.clone();
+        provider.start(RetryConfig::default());
+
+        // Subscribing through either clone observes the same refresh service
+        let mut stream = provider.subscribe();
+        let mut cloned_stream = cloned_provider.subscribe();
+        for expected_token in [MOCKED_TOKEN_1.as_str(), MOCKED_TOKEN_2.as_str()] {
+            assert_eq!(
+                stream.next().await.unwrap().unwrap().password,
+                expected_token
+            );
+            assert_eq!(
+                cloned_stream.next().await.unwrap().unwrap().password,
+                expected_token
+            );
+        }
+
+        // The clones share the refresh service, so starting a clone does not spawn a second one
+        assert!(cloned_provider.background_handle.lock().unwrap().is_some());
+        let credential_provider_ref = Arc::downgrade(&provider.credential_provider);
+        let strong_count = credential_provider_ref.strong_count();
+        let mut cloned_provider = cloned_provider;
+        cloned_provider.start(RetryConfig::default());
+        assert_eq!(credential_provider_ref.strong_count(), strong_count);
+        assert!(call_count_ref.load(Ordering::SeqCst) >= 2);
+    }
+
     #[test]
     fn test_mock_scope_validation() {
         use std::panic;
@@ -1154,6 +1826,39 @@ mod entra_id_mock_tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_mock_provider_stop() {
+        init_logger();
+        let mut provider = create_mock_entra_id_credentials_provider(
+            MockTokenCredential::success(),
+            vec![REDIS_SCOPE_DEFAULT.to_string()],
+        );
+        let credential_provider_ref = Arc::downgrade(&provider.credential_provider);
+
+        provider.start(RetryConfig::default());
+        let mut stream = provider.subscribe();
+        assert!(stream.next().await.unwrap().is_ok());
+        assert_eq!(credential_provider_ref.strong_count(), 2);
+
+        provider.stop().await;
+        assert!(provider.background_handle.lock().unwrap().is_none());
+        assert_eq!(
+            credential_provider_ref.strong_count(),
+            1,
+            "The background task should have ended"
+        );
+        // The last credentials are still available after stopping
+        assert!(provider.current_credentials.read().unwrap().is_some());
+
+        // Stopping again is a no-op
+        provider.stop().await;
+
+        // The provider can be restarted
+        provider.start(RetryConfig::default());
+        assert!(stream.next().await.unwrap().is_ok());
+        provider.stop().await;
+    }
+
     /// This test asserts that the EntraIdCredentialsProvider can be cloned and
     /// dropped without stopping the background task until all instances are dropped.
     #[tokio::test]
@@ -642,6 +642,8 @@ let primary = sentinel.get_async_connection().await.unwrap();
 )]
 
 // public api
+#[cfg(all(feature = "token-based-authentication", feature = "tokio-comp"))]
+pub use crate::auth::PollingStreamingProvider;
 #[cfg(feature = "aio")]
 pub use crate::client::AsyncConnectionConfig;
 pub use crate::client::Client;
@@ -668,12 +670,14 @@ pub use crate::pipeline::Pipeline;
 pub use crate::script::{Script, ScriptInvocation};
 #[cfg(feature = "token-based-authentication")]
 pub use crate::{
-    auth::{BasicAuth, StreamingCredentialsProvider},
-    auth_management::{RetryConfig, TokenRefreshConfig},
+    auth::{BasicAuth, StreamingCredentialsProvider, WithUsername},
+    auth_management::{RefreshEvent, RetryConfig, TokenRefreshConfig},
 };
 #[cfg(feature = "entra-id")]
 pub use {
-    crate::entra_id::{ClientCertificate, EntraIdCredentialsProvider, REDIS_SCOPE_DEFAULT},
+    crate::entra_id::{
+        ClientCertificate, EntraIdCredentialsProvider, REDIS_SCOPE_DEFAULT, ScopeValidation,
+    },
     azure_identity::{
         ClientCertificateCredentialOptions, ClientSecretCredentialOptions,
         DeveloperToolsCredentialOptions, ManagedIdentityCredentialOptions, UserAssignedId,
@@ -831,6 +835,7 @@ mod client;
 mod cmd;
 mod commands;
 mod connection;
+pub mod debug;
 mod errors;
 /// Module for defining I/O behavior.
 pub mod io;